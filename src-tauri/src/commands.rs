@@ -1,52 +1,239 @@
-use crate::db::{get_all_offices, get_table_counts, Office, TableCounts};
+use crate::db::{
+    get_all_offices, get_table_counts, run_migrations, schema_version, CountSnapshot, DbPool, Office, TableCounts,
+};
+use crate::error::LabPulseError;
 use rusqlite::Connection;
 use rusqlite::params;
 use tauri::State;
 use serde::{Deserialize, Serialize};
-use serde_json;
-use std::sync::Mutex;
+use std::collections::{HashMap, HashSet};
 
-pub struct DbConnection(pub Mutex<Connection>);
+pub struct DbConnection(pub DbPool);
 
 #[tauri::command]
-pub fn get_db_table_counts(db: State<DbConnection>) -> Result<TableCounts, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    get_table_counts(&conn).map_err(|e| e.to_string())
+pub fn get_db_table_counts(db: State<DbConnection>) -> Result<TableCounts, LabPulseError> {
+    let conn = db.0.get()?;
+    Ok(get_table_counts(&conn)?)
 }
 
 #[tauri::command]
-pub fn get_offices(db: State<DbConnection>) -> Result<Vec<Office>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    get_all_offices(&conn).map_err(|e| e.to_string())
+pub fn record_counts_snapshot(db: State<DbConnection>) -> Result<CountSnapshot, LabPulseError> {
+    let conn = db.0.get()?;
+    Ok(crate::db::record_counts_snapshot(&conn)?)
 }
 
 #[tauri::command]
-pub fn get_db_path(app: tauri::AppHandle) -> Result<String, String> {
+pub fn get_count_history(db: State<DbConnection>) -> Result<Vec<CountSnapshot>, LabPulseError> {
+    let conn = db.0.get()?;
+    Ok(crate::db::get_count_history(&conn)?)
+}
+
+#[tauri::command]
+pub fn get_offices(db: State<DbConnection>) -> Result<Vec<Office>, LabPulseError> {
+    let conn = db.0.get()?;
+    Ok(get_all_offices(&conn)?)
+}
+
+#[tauri::command]
+pub fn get_db_path(app: tauri::AppHandle) -> Result<String, LabPulseError> {
     use tauri::Manager;
     let app_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| LabPulseError::Io(e.to_string()))?;
     let db_path = app_dir.join("labpulse.db");
     Ok(db_path.to_string_lossy().to_string())
 }
 
-use crate::imports::{import_offices, import_staff, import_contacts, ImportSummary};
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TableRowCount {
+    pub table: String,
+    pub rows: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub ok: bool,
+    pub db_path: String,
+    pub integrity_check: Vec<String>,
+    pub schema_version: i64,
+    pub table_counts: Vec<TableRowCount>,
+    pub sqlite_version: String,
+}
+
+// One-call diagnostics for support: where the database lives, whether SQLite
+// considers it intact, which migrations it has, and how many rows each table holds.
+// `ok` is false unless integrity_check reports exactly "ok".
+#[tauri::command]
+pub fn db_health_check(app: tauri::AppHandle, db: State<DbConnection>) -> Result<HealthReport, LabPulseError> {
+    let db_path = get_db_path(app)?;
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+    let integrity_check = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    let ok = integrity_check.len() == 1 && integrity_check[0] == "ok";
+    
+    let mut stmt = conn.prepare(
+        "SELECT name FROM sqlite_master
+         WHERE type = 'table' AND name NOT LIKE 'sqlite_%'
+         ORDER BY name",
+    )?;
+    let tables = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut table_counts = Vec::with_capacity(tables.len());
+    for table in tables {
+        let rows: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM \"{}\"", table.replace('"', "\"\"")),
+            [],
+            |row| row.get(0),
+        )?;
+        table_counts.push(TableRowCount { table, rows });
+    }
+    
+    Ok(HealthReport {
+        ok,
+        db_path,
+        integrity_check,
+        schema_version: schema_version(&conn)?,
+        table_counts,
+        sqlite_version: rusqlite::version().to_string(),
+    })
+}
+
+// Copy the live database to dest_path with SQLite's online backup API, which is
+// safe while other connections are writing; returns the backup's size in bytes
+#[tauri::command]
+pub fn backup_database(db: State<DbConnection>, dest_path: String) -> Result<u64, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    if dest_path.trim().is_empty() {
+        return Err(LabPulseError::Validation("Backup destination is required".to_string()));
+    }
+    if let Some(live_path) = conn.path() {
+        if std::path::Path::new(live_path) == std::path::Path::new(&dest_path) {
+            return Err(LabPulseError::Validation(
+                "Backup destination must differ from the live database".to_string(),
+            ));
+        }
+    }
+    
+    conn.backup(rusqlite::DatabaseName::Main, &dest_path, None)?;
+    
+    let size = std::fs::metadata(&dest_path)
+        .map_err(|e| LabPulseError::Io(format!("Backup written but could not be read back: {}", e)))?
+        .len();
+    Ok(size)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OptimizeResult {
+    pub size_before: u64,
+    pub size_after: u64,
+}
+
+// Run PRAGMA optimize and VACUUM to reclaim space left by imports and deletes.
+// VACUUM rewrites the whole file and cannot run while any other connection has
+// a transaction open, so call this only while the UI is idle (no import, save,
+// or report in flight); otherwise it fails once the busy timeout expires.
+#[tauri::command]
+pub fn optimize_database(db: State<DbConnection>) -> Result<OptimizeResult, LabPulseError> {
+    let conn = db.0.get()?;
+    let db_path = conn
+        .path()
+        .map(|p| p.to_string())
+        .ok_or_else(|| LabPulseError::Io("Database has no file on disk".to_string()))?;
+    
+    let file_size = |path: &str| {
+        std::fs::metadata(path)
+            .map(|m| m.len())
+            .map_err(|e| LabPulseError::Io(format!("Cannot read database file size: {}", e)))
+    };
+    
+    let size_before = file_size(&db_path)?;
+    
+    conn.execute_batch("PRAGMA optimize; VACUUM;")?;
+    // In WAL mode the vacuumed pages land in the -wal file; checkpoint them back
+    // so the main file actually shrinks
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+    
+    let size_after = file_size(&db_path)?;
+    Ok(OptimizeResult { size_before, size_after })
+}
+
+// Replace the live database with the contents of a backup file. The source is
+// checked first: it must open as SQLite and contain an offices table.
+//
+// The pool in DbConnection is not swapped. The backup API copies the source's
+// pages into the live file under SQLite's own locking, so every pooled
+// connection sees the restored data on its next query. Migrations then run so
+// a backup from an older version gains any tables or columns added since.
+#[tauri::command]
+pub fn restore_database(db: State<DbConnection>, src_path: String) -> Result<(), LabPulseError> {
+    use rusqlite::OpenFlags;
+    
+    let mut conn = db.0.get()?;
+    
+    if let Some(live_path) = conn.path() {
+        if std::path::Path::new(live_path) == std::path::Path::new(&src_path) {
+            return Err(LabPulseError::Validation(
+                "Restore source must differ from the live database".to_string(),
+            ));
+        }
+    }
+    
+    let source = Connection::open_with_flags(&src_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| LabPulseError::Validation(format!("Cannot open backup file: {}", e)))?;
+    let has_offices: bool = source
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'offices')",
+            [],
+            |row| row.get(0),
+        )
+        .map_err(|e| LabPulseError::Validation(format!("Not a readable SQLite database: {}", e)))?;
+    if !has_offices {
+        return Err(LabPulseError::Validation(
+            "Backup file has no offices table; it is not a LabPulse database".to_string(),
+        ));
+    }
+    drop(source);
+    
+    conn.restore(rusqlite::DatabaseName::Main, &src_path, None::<fn(rusqlite::backup::Progress)>)?;
+    run_migrations(&conn)?;
+    
+    Ok(())
+}
+
+use crate::imports::{
+    duplicate_import_warning, file_content_hash, import_contacts, import_offices,
+    import_offices_csv, import_staff, ImportSummary,
+};
 
+// Picks the CSV parser for .csv/.tsv/.txt files, the Excel one otherwise
 #[tauri::command]
-pub fn import_offices_file(db: State<DbConnection>, file_path: String) -> Result<ImportSummary, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    import_offices(&file_path, &conn).map_err(|e| e.to_string())
+pub fn import_offices_file(db: State<DbConnection>, file_path: String) -> Result<ImportSummary, LabPulseError> {
+    let conn = db.0.get()?;
+    let extension = std::path::Path::new(&file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("csv") | Some("tsv") | Some("txt") => import_offices_csv(&file_path, &conn),
+        _ => import_offices(&file_path, &conn),
+    }
 }
 
 #[tauri::command]
-pub fn import_staff_file(db: State<DbConnection>, file_path: String) -> Result<ImportSummary, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    import_staff(&file_path, &conn).map_err(|e| e.to_string())
+pub fn import_staff_file(db: State<DbConnection>, file_path: String) -> Result<ImportSummary, LabPulseError> {
+    let conn = db.0.get()?;
+    import_staff(&file_path, &conn)
 }
 
 #[tauri::command]
-pub fn import_contacts_file(db: State<DbConnection>, file_path: String) -> Result<ImportSummary, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    import_contacts(&file_path, &conn).map_err(|e| e.to_string())
+pub fn import_contacts_file(db: State<DbConnection>, file_path: String) -> Result<ImportSummary, LabPulseError> {
+    let conn = db.0.get()?;
+    import_contacts(&file_path, &conn)
 }
 
 // Financial data structure
@@ -69,8 +256,56 @@ pub struct FinancialData {
     pub bonus_exp: f64,
 }
 
+// Map a monthly_financials row selected in FinancialData field order
+fn financial_data_from_row(row: &rusqlite::Row) -> rusqlite::Result<FinancialData> {
+    Ok(FinancialData {
+        id: row.get(0)?,
+        office_id: row.get(1)?,
+        year: row.get(2)?,
+        month: row.get(3)?,
+        revenue: row.get(4)?,
+        lab_exp_no_outside: row.get(5)?,
+        lab_exp_with_outside: row.get(6)?,
+        outside_lab_spend: row.get(7)?,
+        teeth_supplies: row.get(8)?,
+        lab_supplies: row.get(9)?,
+        lab_hub: row.get(10)?,
+        lss_expense: row.get(11)?,
+        personnel_exp: row.get(12)?,
+        overtime_exp: row.get(13)?,
+        bonus_exp: row.get(14)?,
+    })
+}
+
+// Lab expense including outside labs can't be below lab expense without them,
+// or the derived outside lab spend would go negative
+fn validate_lab_expenses(lab_exp_no_outside: f64, lab_exp_with_outside: f64) -> Result<(), LabPulseError> {
+    if lab_exp_with_outside < lab_exp_no_outside {
+        return Err(LabPulseError::Validation(format!(
+            "lab_exp_with_outside ({:.2}) must not be less than lab_exp_no_outside ({:.2})",
+            lab_exp_with_outside, lab_exp_no_outside
+        )));
+    }
+    Ok(())
+}
+
+fn validate_month(month: i32) -> Result<(), LabPulseError> {
+    if !(1..=12).contains(&month) {
+        return Err(LabPulseError::Validation(format!("Invalid month {} (must be 1-12)", month)));
+    }
+    Ok(())
+}
+
+fn validate_quarter(quarter: i32) -> Result<(), LabPulseError> {
+    if !(1..=4).contains(&quarter) {
+        return Err(LabPulseError::Validation(format!("Invalid quarter {} (must be 1-4)", quarter)));
+    }
+    Ok(())
+}
+
 // Save or update financial data
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub fn save_financial_data(
     db: State<DbConnection>,
     office_id: i64,
@@ -87,33 +322,22 @@ pub fn save_financial_data(
     personnel_exp: f64,
     overtime_exp: f64,
     bonus_exp: f64,
-) -> Result<String, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    
-    conn.execute(
-        "INSERT INTO monthly_financials (
-            office_id, year, month, revenue, lab_exp_no_outside,
-            lab_exp_with_outside, outside_lab_spend, teeth_supplies,
-            lab_supplies, lab_hub, lss_expense, personnel_exp, overtime_exp, bonus_exp
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
-        ON CONFLICT(office_id, year, month) DO UPDATE SET
-            revenue = excluded.revenue,
-            lab_exp_no_outside = excluded.lab_exp_no_outside,
-            lab_exp_with_outside = excluded.lab_exp_with_outside,
-            outside_lab_spend = excluded.outside_lab_spend,
-            teeth_supplies = excluded.teeth_supplies,
-            lab_supplies = excluded.lab_supplies,
-            lab_hub = excluded.lab_hub,
-            lss_expense = excluded.lss_expense,
-            personnel_exp = excluded.personnel_exp,
-            overtime_exp = excluded.overtime_exp,
-            bonus_exp = excluded.bonus_exp",
-        params![
-            office_id, year, month, revenue, lab_exp_no_outside,
-            lab_exp_with_outside, outside_lab_spend, teeth_supplies,
-            lab_supplies, lab_hub, lss_expense, personnel_exp, overtime_exp, bonus_exp
-        ],
-    ).map_err(|e| e.to_string())?;
+) -> Result<String, LabPulseError> {
+    let conn = db.0.get()?;
+    let financials = MonthFinancials {
+        revenue,
+        lab_exp_no_outside,
+        lab_exp_with_outside,
+        outside_lab_spend,
+        teeth_supplies,
+        lab_supplies,
+        lab_hub,
+        lss_expense,
+        personnel_exp,
+        overtime_exp,
+        bonus_exp,
+    };
+    write_financials(&conn, office_id, year, month, &financials)?;
     
     Ok("Financial data saved successfully".to_string())
 }
@@ -125,9 +349,17 @@ pub fn get_financial_data(
     office_id: i64,
     year: i32,
     month: i32,
-) -> Result<Option<FinancialData>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    
+) -> Result<Option<FinancialData>, LabPulseError> {
+    let conn = db.0.get()?;
+    fetch_financial_data(&conn, office_id, year, month)
+}
+
+fn fetch_financial_data(
+    conn: &Connection,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<Option<FinancialData>, LabPulseError> {
     let result = conn.query_row(
         "SELECT id, office_id, year, month, revenue, lab_exp_no_outside,
                 lab_exp_with_outside, outside_lab_spend, teeth_supplies,
@@ -135,31 +367,13 @@ pub fn get_financial_data(
          FROM monthly_financials
          WHERE office_id = ?1 AND year = ?2 AND month = ?3",
         params![office_id, year, month],
-        |row| {
-            Ok(FinancialData {
-                id: row.get(0)?,
-                office_id: row.get(1)?,
-                year: row.get(2)?,
-                month: row.get(3)?,
-                revenue: row.get(4)?,
-                lab_exp_no_outside: row.get(5)?,
-                lab_exp_with_outside: row.get(6)?,
-                outside_lab_spend: row.get(7)?,
-                teeth_supplies: row.get(8)?,
-                lab_supplies: row.get(9)?,
-                lab_hub: row.get(10)?,
-                lss_expense: row.get(11)?,
-                personnel_exp: row.get(12)?,
-                overtime_exp: row.get(13)?,
-                bonus_exp: row.get(14)?,
-            })
-        },
+        financial_data_from_row,
     );
     
     match result {
         Ok(data) => Ok(Some(data)),
         Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -170,15 +384,10 @@ pub fn get_previous_month_financial(
     office_id: i64,
     year: i32,
     month: i32,
-) -> Result<Option<FinancialData>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Option<FinancialData>, LabPulseError> {
+    let conn = db.0.get()?;
     
-    // Calculate previous month
-    let (prev_year, prev_month) = if month == 1 {
-        (year - 1, 12)
-    } else {
-        (year, month - 1)
-    };
+    let (prev_year, prev_month) = previous_month(year, month);
     
     let result = conn.query_row(
         "SELECT id, office_id, year, month, revenue, lab_exp_no_outside,
@@ -187,31 +396,13 @@ pub fn get_previous_month_financial(
          FROM monthly_financials
          WHERE office_id = ?1 AND year = ?2 AND month = ?3",
         params![office_id, prev_year, prev_month],
-        |row| {
-            Ok(FinancialData {
-                id: row.get(0)?,
-                office_id: row.get(1)?,
-                year: row.get(2)?,
-                month: row.get(3)?,
-                revenue: row.get(4)?,
-                lab_exp_no_outside: row.get(5)?,
-                lab_exp_with_outside: row.get(6)?,
-                outside_lab_spend: row.get(7)?,
-                teeth_supplies: row.get(8)?,
-                lab_supplies: row.get(9)?,
-                lab_hub: row.get(10)?,
-                lss_expense: row.get(11)?,
-                personnel_exp: row.get(12)?,
-                overtime_exp: row.get(13)?,
-                bonus_exp: row.get(14)?,
-            })
-        },
+        financial_data_from_row,
     );
     
     match result {
         Ok(data) => Ok(Some(data)),
         Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -229,6 +420,7 @@ pub struct OperationsData {
 
 // Save or update operations data
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub fn save_operations_data(
     db: State<DbConnection>,
     office_id: i64,
@@ -239,60 +431,16 @@ pub fn save_operations_data(
     current_staff: Option<f64>,
     required_staff: Option<f64>,
     staffing_trend: Option<f64>,
-) -> Result<(), String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    
-    // Check if record exists
-    let exists: bool = conn.query_row(
-        "SELECT COUNT(*) FROM monthly_ops WHERE office_id = ?1 AND year = ?2 AND month = ?3",
-        params![office_id, year, month],
-        |row| row.get::<_, i64>(0).map(|count| count > 0)
-    ).map_err(|e| e.to_string())?;
-    
-    if exists {
-        // Update existing record
-        conn.execute(
-            "UPDATE monthly_ops 
-             SET backlog_case_count = ?1, 
-                 overtime_value = ?2,
-                 current_staff = ?3,
-                 required_staff = ?4,
-                 staffing_trend = ?5,
-                 updated_at = CURRENT_TIMESTAMP
-             WHERE office_id = ?6 AND year = ?7 AND month = ?8",
-            params![
-                backlog_case_count,
-                overtime_value,
-                current_staff,
-                required_staff,
-                staffing_trend,
-                office_id,
-                year,
-                month
-            ],
-        ).map_err(|e| e.to_string())?;
-    } else {
-        // Insert new record
-        conn.execute(
-            "INSERT INTO monthly_ops (
-                office_id, year, month, 
-                backlog_case_count, overtime_value,
-                current_staff, required_staff, staffing_trend
-             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-            params![
-                office_id,
-                year,
-                month,
-                backlog_case_count,
-                overtime_value,
-                current_staff,
-                required_staff,
-                staffing_trend,
-            ],
-        ).map_err(|e| e.to_string())?;
-    }
-    
-    Ok(())
+) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    let operations = MonthOperations {
+        backlog_case_count,
+        overtime_value,
+        current_staff,
+        required_staff,
+        staffing_trend,
+    };
+    write_operations(&conn, office_id, year, month, &operations)
 }
 
 // Get operations data including auto-calculated backlog and overtime
@@ -302,8 +450,8 @@ pub fn get_operations_data(
     office_id: i64,
     year: i32,
     month: i32,
-) -> Result<Option<serde_json::Value>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Option<serde_json::Value>, LabPulseError> {
+    let conn = db.0.get()?;
     
     // Get staffing data from monthly_ops
     let ops_result = conn.query_row(
@@ -323,10 +471,12 @@ pub fn get_operations_data(
     let (current_staff, required_staff, staffing_trend) = match ops_result {
         Ok(data) => data,
         Err(rusqlite::Error::QueryReturnedNoRows) => (None, None, None),
-        Err(e) => return Err(e.to_string()),
+        Err(e) => return Err(e.into()),
     };
     
     // Auto-calculate backlog from monthly_volume (average of weekly data)
+    let (week_start, week_end) = month_week_range(year, month)
+        .ok_or_else(|| LabPulseError::Validation("Invalid month".to_string()))?;
     let backlog_case_count: Option<i32> = conn.query_row(
         "SELECT CAST(AVG(
             lab_setups + lab_fixed_cases + lab_over_denture + lab_processes + lab_finishes +
@@ -334,8 +484,8 @@ pub fn get_operations_data(
          ) AS INTEGER)
          FROM weekly_volume
          WHERE office_id = ?1 AND year = ?2 
-         AND week_number >= (?3 - 1) * 4 + 1 AND week_number <= ?3 * 4",
-        params![office_id, year, month],
+         AND week_number BETWEEN ?3 AND ?4",
+        params![office_id, year, week_start, week_end],
         |row| row.get(0)
     ).ok();
     
@@ -370,15 +520,10 @@ pub fn get_previous_month_operations(
     office_id: i64,
     year: i32,
     month: i32,
-) -> Result<Option<OperationsData>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Option<OperationsData>, LabPulseError> {
+    let conn = db.0.get()?;
     
-    // Calculate previous month
-    let (prev_year, prev_month) = if month == 1 {
-        (year - 1, 12)
-    } else {
-        (year, month - 1)
-    };
+    let (prev_year, prev_month) = previous_month(year, month);
     
     let result = conn.query_row(
         "SELECT id, office_id, year, month, backlog_case_count, overtime_value, labor_model_value
@@ -401,7 +546,7 @@ pub fn get_previous_month_operations(
     match result {
         Ok(data) => Ok(Some(data)),
         Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -467,6 +612,7 @@ pub struct WeeklyVolumeData {
 
 // Save or update volume data
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub fn save_volume_data(
     db: State<DbConnection>,
     office_id: i64,
@@ -495,51 +641,34 @@ pub fn save_volume_data(
     remake_units: i32,
     bite_block_units: i32,
     total_weekly_units: i32,
-) -> Result<String, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
-    
-    conn.execute(
-        "INSERT INTO monthly_volume (
-            office_id, year, month, backlog_in_lab, backlog_in_clinic,
-            lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
-            clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
-            immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
-            repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units,
-            total_weekly_units
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26)
-        ON CONFLICT(office_id, year, month) DO UPDATE SET
-            backlog_in_lab = excluded.backlog_in_lab,
-            backlog_in_clinic = excluded.backlog_in_clinic,
-            lab_setups = excluded.lab_setups,
-            lab_fixed_cases = excluded.lab_fixed_cases,
-            lab_over_denture = excluded.lab_over_denture,
-            lab_processes = excluded.lab_processes,
-            lab_finishes = excluded.lab_finishes,
-            clinic_wax_tryin = excluded.clinic_wax_tryin,
-            clinic_delivery = excluded.clinic_delivery,
-            clinic_outside_lab = excluded.clinic_outside_lab,
-            clinic_on_hold = excluded.clinic_on_hold,
-            immediate_units = excluded.immediate_units,
-            economy_units = excluded.economy_units,
-            economy_plus_units = excluded.economy_plus_units,
-            premium_units = excluded.premium_units,
-            ultimate_units = excluded.ultimate_units,
-            repair_units = excluded.repair_units,
-            reline_units = excluded.reline_units,
-            partial_units = excluded.partial_units,
-            retry_units = excluded.retry_units,
-            remake_units = excluded.remake_units,
-            bite_block_units = excluded.bite_block_units,
-            total_weekly_units = excluded.total_weekly_units",
-        params![
-            office_id, year, month, backlog_in_lab, backlog_in_clinic,
-            lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
-            clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
-            immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
-            repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units,
-            total_weekly_units
-        ],
-    ).map_err(|e| e.to_string())?;
+) -> Result<String, LabPulseError> {
+    let conn = db.0.get()?;
+    let volume = MonthVolume {
+        backlog_in_lab,
+        backlog_in_clinic,
+        lab_setups,
+        lab_fixed_cases,
+        lab_over_denture,
+        lab_processes,
+        lab_finishes,
+        clinic_wax_tryin,
+        clinic_delivery,
+        clinic_outside_lab,
+        clinic_on_hold,
+        immediate_units,
+        economy_units,
+        economy_plus_units,
+        premium_units,
+        ultimate_units,
+        repair_units,
+        reline_units,
+        partial_units,
+        retry_units,
+        remake_units,
+        bite_block_units,
+        total_weekly_units,
+    };
+    write_volume(&conn, office_id, year, month, &volume)?;
     
     Ok("Volume data saved successfully".to_string())
 }
@@ -551,8 +680,8 @@ pub fn get_volume_data(
     office_id: i64,
     year: i32,
     month: i32,
-) -> Result<Option<VolumeData>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Option<VolumeData>, LabPulseError> {
+    let conn = db.0.get()?;
     
     let result = conn.query_row(
         "SELECT id, office_id, year, month, backlog_in_lab, backlog_in_clinic,
@@ -600,7 +729,7 @@ pub fn get_volume_data(
     match result {
         Ok(data) => Ok(Some(data)),
         Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -611,15 +740,10 @@ pub fn get_previous_month_volume(
     office_id: i64,
     year: i32,
     month: i32,
-) -> Result<Option<VolumeData>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Option<VolumeData>, LabPulseError> {
+    let conn = db.0.get()?;
     
-    // Calculate previous month
-    let (prev_year, prev_month) = if month == 1 {
-        (year - 1, 12)
-    } else {
-        (year, month - 1)
-    };
+    let (prev_year, prev_month) = previous_month(year, month);
     
     let result = conn.query_row(
         "SELECT id, office_id, year, month, backlog_in_lab, backlog_in_clinic,
@@ -667,61 +791,400 @@ pub fn get_previous_month_volume(
     match result {
         Ok(data) => Ok(Some(data)),
         Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e.into()),
     }
 }
 
-// Get weekly volume records for drill-down view
-#[tauri::command]
-pub fn get_weekly_volume_records(
-    db: State<DbConnection>,
+// Columns copied forward by the copy_previous_month_* commands (everything but keys and timestamps)
+const FINANCIAL_COPY_COLUMNS: &[&str] = &[
+    "revenue", "lab_exp_no_outside", "lab_exp_with_outside", "outside_lab_spend",
+    "teeth_supplies", "lab_supplies", "lab_hub", "lss_expense",
+    "personnel_exp", "overtime_exp", "bonus_exp",
+];
+const OPS_COPY_COLUMNS: &[&str] = &[
+    "backlog_case_count", "overtime_value", "labor_model_value",
+    "current_staff", "required_staff", "staffing_trend",
+];
+const VOLUME_COPY_COLUMNS: &[&str] = &[
+    "backlog_in_lab", "backlog_in_clinic",
+    "lab_setups", "lab_fixed_cases", "lab_over_denture", "lab_processes", "lab_finishes",
+    "clinic_wax_tryin", "clinic_delivery", "clinic_outside_lab", "clinic_on_hold",
+    "immediate_units", "economy_units", "economy_plus_units", "premium_units", "ultimate_units",
+    "repair_units", "reline_units", "partial_units", "retry_units", "remake_units", "bite_block_units",
+    "total_weekly_units",
+];
+
+// Seed an office's month in `table` with a copy of the previous month's row.
+// Refuses to touch a month that already has data.
+fn copy_previous_month_row(
+    conn: &Connection,
+    table: &str,
+    label: &str,
+    columns: &[&str],
     office_id: i64,
     year: i32,
     month: i32,
-) -> Result<Vec<WeeklyVolumeData>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<(), LabPulseError> {
+    validate_month(month)?;
     
-    // Calculate week range for this month
-    let (week_start, week_end) = match month {
-        1 => (1, 4), 2 => (5, 8), 3 => (9, 13), 4 => (14, 17),
-        5 => (18, 22), 6 => (23, 26), 7 => (27, 30), 8 => (31, 35),
-        9 => (36, 39), 10 => (40, 43), 11 => (44, 48), 12 => (49, 53),
-        _ => return Err("Invalid month".to_string()),
-    };
+    let exists: bool = conn.query_row(
+        &format!("SELECT EXISTS(SELECT 1 FROM {} WHERE office_id = ?1 AND year = ?2 AND month = ?3)", table),
+        params![office_id, year, month],
+        |row| row.get(0),
+    )?;
+    if exists {
+        return Err(LabPulseError::Validation(format!(
+            "Office {} already has {} data for {}/{}",
+            office_id, label, month, year
+        )));
+    }
     
-    let mut stmt = conn.prepare(
-        "SELECT id, office_id, year, week_number,
-                lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
-                clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
-                immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
-                repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units
-         FROM weekly_volume
-         WHERE office_id = ?1 AND year = ?2 AND week_number BETWEEN ?3 AND ?4
-         ORDER BY week_number ASC"
-    ).map_err(|e| e.to_string())?;
+    let (prev_year, prev_month) = previous_month(year, month);
+    let column_list = columns.join(", ");
+    let copied = conn.execute(
+        &format!(
+            "INSERT INTO {table} (office_id, year, month, {cols})
+             SELECT office_id, ?2, ?3, {cols} FROM {table}
+             WHERE office_id = ?1 AND year = ?4 AND month = ?5",
+            table = table,
+            cols = column_list
+        ),
+        params![office_id, year, month, prev_year, prev_month],
+    )?;
     
-    let weekly_records = stmt.query_map(
-        params![office_id, year, week_start, week_end],
-        |row| {
-            Ok(WeeklyVolumeData {
-                id: row.get(0)?,
-                office_id: row.get(1)?,
-                year: row.get(2)?,
-                week_number: row.get(3)?,
-                lab_setups: row.get(4)?,
-                lab_fixed_cases: row.get(5)?,
-                lab_over_denture: row.get(6)?,
-                lab_processes: row.get(7)?,
-                lab_finishes: row.get(8)?,
-                clinic_wax_tryin: row.get(9)?,
-                clinic_delivery: row.get(10)?,
-                clinic_outside_lab: row.get(11)?,
-                clinic_on_hold: row.get(12)?,
-                immediate_units: row.get(13)?,
-                economy_units: row.get(14)?,
-                economy_plus_units: row.get(15)?,
-                premium_units: row.get(16)?,
-                ultimate_units: row.get(17)?,
+    if copied == 0 {
+        return Err(LabPulseError::NotFound(format!(
+            "No {} data for office {} in {}/{} to copy",
+            label, office_id, prev_month, prev_year
+        )));
+    }
+    Ok(())
+}
+
+// Start a month's financials as a copy of the previous month
+#[tauri::command]
+pub fn copy_previous_month_financials(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    copy_previous_month_row(&conn, "monthly_financials", "financial", FINANCIAL_COPY_COLUMNS, office_id, year, month)
+}
+
+// Start a month's operations data as a copy of the previous month
+#[tauri::command]
+pub fn copy_previous_month_operations(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    copy_previous_month_row(&conn, "monthly_ops", "operations", OPS_COPY_COLUMNS, office_id, year, month)
+}
+
+// Start a month's volume data as a copy of the previous month
+#[tauri::command]
+pub fn copy_previous_month_volume(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    copy_previous_month_row(&conn, "monthly_volume", "volume", VOLUME_COPY_COLUMNS, office_id, year, month)
+}
+
+// Sections of a month bundle. Each mirrors the arguments of the matching
+// save_* command, minus office/year/month which the bundle supplies once.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthFinancials {
+    pub revenue: f64,
+    pub lab_exp_no_outside: f64,
+    pub lab_exp_with_outside: f64,
+    pub outside_lab_spend: f64,
+    pub teeth_supplies: f64,
+    pub lab_supplies: f64,
+    pub lab_hub: f64,
+    pub lss_expense: f64,
+    pub personnel_exp: f64,
+    pub overtime_exp: f64,
+    pub bonus_exp: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthOperations {
+    pub backlog_case_count: Option<i32>,
+    pub overtime_value: Option<f64>,
+    pub current_staff: Option<f64>,
+    pub required_staff: Option<f64>,
+    pub staffing_trend: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthVolume {
+    pub backlog_in_lab: i32,
+    pub backlog_in_clinic: i32,
+    pub lab_setups: i32,
+    pub lab_fixed_cases: i32,
+    pub lab_over_denture: i32,
+    pub lab_processes: i32,
+    pub lab_finishes: i32,
+    pub clinic_wax_tryin: i32,
+    pub clinic_delivery: i32,
+    pub clinic_outside_lab: i32,
+    pub clinic_on_hold: i32,
+    pub immediate_units: i32,
+    pub economy_units: i32,
+    pub economy_plus_units: i32,
+    pub premium_units: i32,
+    pub ultimate_units: i32,
+    pub repair_units: i32,
+    pub reline_units: i32,
+    pub partial_units: i32,
+    pub retry_units: i32,
+    pub remake_units: i32,
+    pub bite_block_units: i32,
+    pub total_weekly_units: i32,
+}
+
+// Upsert one month of financials; shared by save_financial_data and save_month_bundle
+fn write_financials(
+    conn: &Connection,
+    office_id: i64,
+    year: i32,
+    month: i32,
+    f: &MonthFinancials,
+) -> Result<(), LabPulseError> {
+    validate_lab_expenses(f.lab_exp_no_outside, f.lab_exp_with_outside)?;
+    
+    conn.execute(
+        "INSERT INTO monthly_financials (
+            office_id, year, month, revenue, lab_exp_no_outside,
+            lab_exp_with_outside, outside_lab_spend, teeth_supplies,
+            lab_supplies, lab_hub, lss_expense, personnel_exp, overtime_exp, bonus_exp
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+        ON CONFLICT(office_id, year, month) DO UPDATE SET
+            revenue = excluded.revenue,
+            lab_exp_no_outside = excluded.lab_exp_no_outside,
+            lab_exp_with_outside = excluded.lab_exp_with_outside,
+            outside_lab_spend = excluded.outside_lab_spend,
+            teeth_supplies = excluded.teeth_supplies,
+            lab_supplies = excluded.lab_supplies,
+            lab_hub = excluded.lab_hub,
+            lss_expense = excluded.lss_expense,
+            personnel_exp = excluded.personnel_exp,
+            overtime_exp = excluded.overtime_exp,
+            bonus_exp = excluded.bonus_exp",
+        params![
+            office_id, year, month, f.revenue, f.lab_exp_no_outside,
+            f.lab_exp_with_outside, f.outside_lab_spend, f.teeth_supplies,
+            f.lab_supplies, f.lab_hub, f.lss_expense, f.personnel_exp, f.overtime_exp, f.bonus_exp
+        ],
+    )?;
+    
+    Ok(())
+}
+
+// Insert or update one month of operations; shared by save_operations_data and save_month_bundle
+fn write_operations(
+    conn: &Connection,
+    office_id: i64,
+    year: i32,
+    month: i32,
+    ops: &MonthOperations,
+) -> Result<(), LabPulseError> {
+    // Check if record exists
+    let exists: bool = conn.query_row(
+        "SELECT COUNT(*) FROM monthly_ops WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| row.get::<_, i64>(0).map(|count| count > 0)
+    )?;
+    
+    if exists {
+        // Update existing record
+        conn.execute(
+            "UPDATE monthly_ops 
+             SET backlog_case_count = ?1, 
+                 overtime_value = ?2,
+                 current_staff = ?3,
+                 required_staff = ?4,
+                 staffing_trend = ?5,
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE office_id = ?6 AND year = ?7 AND month = ?8",
+            params![
+                ops.backlog_case_count,
+                ops.overtime_value,
+                ops.current_staff,
+                ops.required_staff,
+                ops.staffing_trend,
+                office_id,
+                year,
+                month
+            ],
+        )?;
+    } else {
+        // Insert new record
+        conn.execute(
+            "INSERT INTO monthly_ops (
+                office_id, year, month, 
+                backlog_case_count, overtime_value,
+                current_staff, required_staff, staffing_trend
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                office_id,
+                year,
+                month,
+                ops.backlog_case_count,
+                ops.overtime_value,
+                ops.current_staff,
+                ops.required_staff,
+                ops.staffing_trend,
+            ],
+        )?;
+    }
+    
+    Ok(())
+}
+
+// Upsert one month of volume; shared by save_volume_data and save_month_bundle
+fn write_volume(
+    conn: &Connection,
+    office_id: i64,
+    year: i32,
+    month: i32,
+    v: &MonthVolume,
+) -> Result<(), LabPulseError> {
+    conn.execute(
+        "INSERT INTO monthly_volume (
+            office_id, year, month, backlog_in_lab, backlog_in_clinic,
+            lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
+            clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
+            immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
+            repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units,
+            total_weekly_units
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26)
+        ON CONFLICT(office_id, year, month) DO UPDATE SET
+            backlog_in_lab = excluded.backlog_in_lab,
+            backlog_in_clinic = excluded.backlog_in_clinic,
+            lab_setups = excluded.lab_setups,
+            lab_fixed_cases = excluded.lab_fixed_cases,
+            lab_over_denture = excluded.lab_over_denture,
+            lab_processes = excluded.lab_processes,
+            lab_finishes = excluded.lab_finishes,
+            clinic_wax_tryin = excluded.clinic_wax_tryin,
+            clinic_delivery = excluded.clinic_delivery,
+            clinic_outside_lab = excluded.clinic_outside_lab,
+            clinic_on_hold = excluded.clinic_on_hold,
+            immediate_units = excluded.immediate_units,
+            economy_units = excluded.economy_units,
+            economy_plus_units = excluded.economy_plus_units,
+            premium_units = excluded.premium_units,
+            ultimate_units = excluded.ultimate_units,
+            repair_units = excluded.repair_units,
+            reline_units = excluded.reline_units,
+            partial_units = excluded.partial_units,
+            retry_units = excluded.retry_units,
+            remake_units = excluded.remake_units,
+            bite_block_units = excluded.bite_block_units,
+            total_weekly_units = excluded.total_weekly_units",
+        params![
+            office_id, year, month, v.backlog_in_lab, v.backlog_in_clinic,
+            v.lab_setups, v.lab_fixed_cases, v.lab_over_denture, v.lab_processes, v.lab_finishes,
+            v.clinic_wax_tryin, v.clinic_delivery, v.clinic_outside_lab, v.clinic_on_hold,
+            v.immediate_units, v.economy_units, v.economy_plus_units, v.premium_units, v.ultimate_units,
+            v.repair_units, v.reline_units, v.partial_units, v.retry_units, v.remake_units, v.bite_block_units,
+            v.total_weekly_units
+        ],
+    )?;
+    
+    Ok(())
+}
+
+// Save any combination of financials, operations, and volume for one month in
+// a single transaction. Sections left out are not touched; if any section
+// fails, nothing is written.
+#[tauri::command]
+pub fn save_month_bundle(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+    financials: Option<MonthFinancials>,
+    operations: Option<MonthOperations>,
+    volume: Option<MonthVolume>,
+) -> Result<(), LabPulseError> {
+    validate_month(month)?;
+    if financials.is_none() && operations.is_none() && volume.is_none() {
+        return Err(LabPulseError::Validation("Nothing to save: no sections provided".to_string()));
+    }
+    
+    let conn = db.0.get()?;
+    let tx = conn.unchecked_transaction()?;
+    
+    if let Some(f) = &financials {
+        write_financials(&tx, office_id, year, month, f)?;
+    }
+    if let Some(ops) = &operations {
+        write_operations(&tx, office_id, year, month, ops)?;
+    }
+    if let Some(v) = &volume {
+        write_volume(&tx, office_id, year, month, v)?;
+    }
+    
+    tx.commit()?;
+    Ok(())
+}
+
+// Get weekly volume records for drill-down view
+#[tauri::command]
+pub fn get_weekly_volume_records(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<Vec<WeeklyVolumeData>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    // Calculate week range for this month
+    let (week_start, week_end) = month_week_range(year, month)
+        .ok_or_else(|| LabPulseError::Validation("Invalid month".to_string()))?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT id, office_id, year, week_number,
+                lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
+                clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
+                immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
+                repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units
+         FROM weekly_volume
+         WHERE office_id = ?1 AND year = ?2 AND week_number BETWEEN ?3 AND ?4
+         ORDER BY week_number ASC"
+    )?;
+    
+    let weekly_records = stmt.query_map(
+        params![office_id, year, week_start, week_end],
+        |row| {
+            Ok(WeeklyVolumeData {
+                id: row.get(0)?,
+                office_id: row.get(1)?,
+                year: row.get(2)?,
+                week_number: row.get(3)?,
+                lab_setups: row.get(4)?,
+                lab_fixed_cases: row.get(5)?,
+                lab_over_denture: row.get(6)?,
+                lab_processes: row.get(7)?,
+                lab_finishes: row.get(8)?,
+                clinic_wax_tryin: row.get(9)?,
+                clinic_delivery: row.get(10)?,
+                clinic_outside_lab: row.get(11)?,
+                clinic_on_hold: row.get(12)?,
+                immediate_units: row.get(13)?,
+                economy_units: row.get(14)?,
+                economy_plus_units: row.get(15)?,
+                premium_units: row.get(16)?,
+                ultimate_units: row.get(17)?,
                 repair_units: row.get(18)?,
                 reline_units: row.get(19)?,
                 partial_units: row.get(20)?,
@@ -730,9 +1193,8 @@ pub fn get_weekly_volume_records(
                 bite_block_units: row.get(23)?,
             })
         },
-    ).map_err(|e| e.to_string())?
-    .collect::<Result<Vec<_>, _>>()
-    .map_err(|e| e.to_string())?;
+    )?
+    .collect::<Result<Vec<_>, _>>()?;
     
     Ok(weekly_records)
 }
@@ -745,8 +1207,8 @@ pub fn save_note(
     year: i32,
     month: i32,
     note_text: String,
-) -> Result<String, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<String, LabPulseError> {
+    let conn = db.0.get()?;
     
     conn.execute(
         "INSERT INTO notes_actions (office_id, year, month, note_text)
@@ -755,7 +1217,7 @@ pub fn save_note(
              note_text = excluded.note_text,
              updated_at = CURRENT_TIMESTAMP",
         params![office_id, year, month, note_text],
-    ).map_err(|e| e.to_string())?;
+    )?;
     
     Ok("Note saved successfully".to_string())
 }
@@ -767,8 +1229,8 @@ pub fn get_notes(
     office_id: i64,
     year: i32,
     month: i32,
-) -> Result<Option<String>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Option<String>, LabPulseError> {
+    let conn = db.0.get()?;
     
     let result = conn.query_row(
         "SELECT note_text FROM notes_actions
@@ -780,7 +1242,7 @@ pub fn get_notes(
     match result {
         Ok(note_text) => Ok(Some(note_text)),
         Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -812,13 +1274,128 @@ pub fn get_dashboard_data(
     start_month: i32,
     end_year: i32,
     end_month: i32,
-) -> Result<Vec<OfficeSummary>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Vec<OfficeSummary>, LabPulseError> {
+    let conn = db.0.get()?;
+    build_dashboard(&conn, start_year, start_month, end_year, end_month)
+}
+
+// One office's financial inputs for the dashboard period
+struct DashboardFinancials {
+    revenue: Option<f64>,
+    lab_exp: Option<f64>,
+    personnel_exp: Option<f64>,
+    overtime_exp: Option<f64>,
+}
+
+// Build the per-office dashboard summaries for a period. Each data source is
+// read with one set-based query and joined to the office list in memory.
+// A row that fails to decode counts as no data for that office.
+fn build_dashboard(
+    conn: &Connection,
+    start_year: i32,
+    start_month: i32,
+    end_year: i32,
+    end_month: i32,
+) -> Result<Vec<OfficeSummary>, LabPulseError> {
+    // Check if this is a single month or multi-month period
+    let is_single_month = start_year == end_year && start_month == end_month;
+    let range = params![start_year, start_month, end_year, end_month];
+    let in_range = "(year * 100 + month) BETWEEN (?1 * 100 + ?2) AND (?3 * 100 + ?4)";
+    // Single-month queries bind only the start year and month
+    let period = if is_single_month { &range[..2] } else { range };
     
-    // Get all offices
-    let mut stmt = conn.prepare(
-        "SELECT office_id, office_name, model, dfo FROM offices ORDER BY office_id"
-    ).map_err(|e| e.to_string())?;
+    // Financials - actual values for a single month, SUM for multi-month
+    let financial_query = if is_single_month {
+        "SELECT office_id, revenue, lab_exp_with_outside, personnel_exp, overtime_exp
+         FROM monthly_financials
+         WHERE year = ?1 AND month = ?2".to_string()
+    } else {
+        format!(
+            "SELECT office_id, SUM(revenue), SUM(lab_exp_with_outside), SUM(personnel_exp), SUM(overtime_exp)
+             FROM monthly_financials
+             WHERE {}
+             GROUP BY office_id",
+            in_range
+        )
+    };
+    let mut stmt = conn.prepare(&financial_query)?;
+    let financials: HashMap<i64, DashboardFinancials> = stmt
+        .query_map(period, |row| {
+            Ok((row.get(0)?, DashboardFinancials {
+                revenue: row.get(1)?,
+                lab_exp: row.get(2)?,
+                personnel_exp: row.get(3)?,
+                overtime_exp: row.get(4)?,
+            }))
+        })?
+        .filter_map(Result::ok)
+        .collect();
+    
+    // Operations - actual backlog for a single month, rounded AVG for multi-month.
+    // A backlog that isn't a whole number counts as missing for a single month.
+    let backlogs: HashMap<i64, Option<i32>> = if is_single_month {
+        let mut stmt = conn.prepare(
+            "SELECT office_id, backlog_case_count
+             FROM monthly_ops
+             WHERE year = ?1 AND month = ?2"
+        )?;
+        let rows = stmt
+            .query_map(period, |row| {
+                Ok((row.get(0)?, row.get::<_, Option<i32>>(1).ok().flatten()))
+            })?
+            .filter_map(Result::ok)
+            .collect();
+        rows
+    } else {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT office_id, AVG(backlog_case_count)
+             FROM monthly_ops
+             WHERE {}
+             GROUP BY office_id",
+            in_range
+        ))?;
+        let rows = stmt
+            .query_map(period, |row| {
+                Ok((row.get(0)?, row.get::<_, Option<f64>>(1)?.map(|avg| avg.round() as i32)))
+            })?
+            .filter_map(Result::ok)
+            .collect();
+        rows
+    };
+    
+    // Offices with any volume or notes in the date range
+    let offices_with_rows = |table: &str| -> rusqlite::Result<HashSet<i64>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT DISTINCT office_id FROM {} WHERE {}",
+            table, in_range
+        ))?;
+        let ids = stmt.query_map(range, |row| row.get(0))?.collect();
+        ids
+    };
+    let with_volume = offices_with_rows("monthly_volume")?;
+    let with_notes = offices_with_rows("notes_actions")?;
+    
+    // Latest month with any data per office (across all time, not just range)
+    let mut stmt = conn.prepare(
+        "SELECT office_id, MAX(year * 100 + month) FROM (
+            SELECT office_id, year, month FROM monthly_financials
+            UNION
+            SELECT office_id, year, month FROM monthly_ops
+            UNION
+            SELECT office_id, year, month FROM monthly_volume
+         ) GROUP BY office_id"
+    )?;
+    let latest: HashMap<i64, (i32, i32)> = stmt
+        .query_map([], |row| {
+            let period: i32 = row.get(1)?;
+            Ok((row.get(0)?, (period / 100, period % 100)))
+        })?
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    
+    // Get all offices
+    let mut stmt = conn.prepare(
+        "SELECT office_id, office_name, model, dfo FROM offices ORDER BY office_id"
+    )?;
     
     let offices = stmt.query_map([], |row| {
         Ok((
@@ -827,209 +1404,51 @@ pub fn get_dashboard_data(
             row.get::<_, String>(2)?,
             row.get::<_, Option<String>>(3)?,
         ))
-    }).map_err(|e| e.to_string())?;
+    })?;
     
     let mut summaries = Vec::new();
     
-    // Check if this is a single month or multi-month period
-    let is_single_month = start_year == end_year && start_month == end_month;
-    
     for office in offices {
-        let (office_id, office_name, model, dfo) = office.map_err(|e| e.to_string())?;
-        
-        // Get financial data - use actual values for single month, SUM for multi-month
-        let (financial_query, calc_percentages) = if is_single_month {
-            (
-                "SELECT 
-                    revenue,
-                    lab_exp_with_outside,
-                    personnel_exp,
-                    overtime_exp,
-                    year,
-                    month
-                 FROM monthly_financials
-                 WHERE office_id = ?1
-                   AND year = ?2 AND month = ?3",
-                true
-            )
-        } else {
-            (
-                "SELECT 
-                    SUM(revenue),
-                    SUM(lab_exp_with_outside),
-                    SUM(personnel_exp),
-                    SUM(overtime_exp),
-                    MAX(year),
-                    MAX(month)
-                 FROM monthly_financials
-                 WHERE office_id = ?1
-                   AND (year * 100 + month) BETWEEN (?2 * 100 + ?3) AND (?4 * 100 + ?5)",
-                false
-            )
-        };
-        
-        let financial_result = if is_single_month {
-            conn.query_row(
-                financial_query,
-                params![office_id, start_year, start_month],
-                |row| {
-                    Ok((
-                        row.get::<_, Option<f64>>(0)?,  // revenue
-                        row.get::<_, Option<f64>>(1)?,  // lab_exp_with_outside
-                        row.get::<_, Option<f64>>(2)?,  // personnel_exp
-                        row.get::<_, Option<f64>>(3)?,  // overtime_exp
-                        row.get::<_, Option<i32>>(4)?,  // year
-                        row.get::<_, Option<i32>>(5)?,  // month
-                    ))
-                },
-            )
-        } else {
-            conn.query_row(
-                financial_query,
-                params![office_id, start_year, start_month, end_year, end_month],
-                |row| {
-                    Ok((
-                        row.get::<_, Option<f64>>(0)?,  // SUM(revenue)
-                        row.get::<_, Option<f64>>(1)?,  // SUM(lab_exp_with_outside)
-                        row.get::<_, Option<f64>>(2)?,  // SUM(personnel_exp)
-                        row.get::<_, Option<f64>>(3)?,  // SUM(overtime_exp)
-                        row.get::<_, Option<i32>>(4)?,  // MAX(year)
-                        row.get::<_, Option<i32>>(5)?,  // MAX(month)
-                    ))
-                },
-            )
-        };
+        let (office_id, office_name, model, dfo) = office?;
         
-        let (revenue, lab_exp, personnel_exp, overtime_exp, has_financial) = match financial_result {
-            Ok((Some(rev), Some(lab), Some(pers), Some(ot), _, _)) => {
+        let inputs = financials
+            .get(&office_id)
+            .map(|f| (f.revenue, f.lab_exp, f.personnel_exp, f.overtime_exp));
+        let (revenue, lab_exp, personnel_exp, overtime_exp, has_financial) = match inputs {
+            Some((Some(rev), Some(lab), Some(pers), Some(ot))) => {
                 (Some(rev), Some(lab), Some(pers), Some(ot), true)
             },
-            Ok((Some(rev), Some(lab), Some(pers), None, _, _)) => {
+            Some((Some(rev), Some(lab), Some(pers), None)) => {
                 (Some(rev), Some(lab), Some(pers), None, true)
             },
-            Ok((Some(rev), Some(lab), None, None, _, _)) => {
+            Some((Some(rev), Some(lab), None, None)) => {
                 (Some(rev), Some(lab), None, None, true)
             },
-            Ok((Some(rev), None, None, None, _, _)) => {
+            Some((Some(rev), None, None, None)) => {
                 (Some(rev), None, None, None, true)
             },
-            Ok((None, _, _, _, _, _)) => (None, None, None, None, false),
-            Ok(_) => {
-                // Partial data - treat as no financial data
-                (None, None, None, None, false)
-            },
-            Err(_) => (None, None, None, None, false),
+            // No row, no revenue, or partial data - treat as no financial data
+            _ => (None, None, None, None, false),
         };
         
         // Calculate percentages only for single month periods
-        let (lab_exp_percent, personnel_percent, overtime_percent) = if calc_percentages {
-            // Calculate percentages for single month
-            let lab_pct = if let (Some(rev), Some(lab)) = (revenue, lab_exp) {
-                if rev > 0.0 {
-                    Some((lab / rev) * 100.0)
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
-            
-            let pers_pct = if let (Some(rev), Some(pers)) = (revenue, personnel_exp) {
-                if rev > 0.0 {
-                    Some((pers / rev) * 100.0)
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
-            
-            let ot_pct = if let (Some(rev), Some(ot)) = (revenue, overtime_exp) {
-                if rev > 0.0 {
-                    Some((ot / rev) * 100.0)
-                } else {
-                    None
-                }
-            } else {
-                None
+        let (lab_exp_percent, personnel_percent, overtime_percent) = if is_single_month {
+            let percent = |value: Option<f64>| match (revenue, value) {
+                (Some(rev), Some(v)) if rev > 0.0 => Some((v / rev) * 100.0),
+                _ => None,
             };
-            
-            (lab_pct, pers_pct, ot_pct)
+            (percent(lab_exp), percent(personnel_exp), percent(overtime_exp))
         } else {
             // Multi-month period: no percentages
             (None, None, None)
         };
         
-        // Get operations data - use actual value for single month, AVG for multi-month
-        let operations_query = if is_single_month {
-            "SELECT backlog_case_count 
-             FROM monthly_ops 
-             WHERE office_id = ?1 AND year = ?2 AND month = ?3"
-        } else {
-            "SELECT AVG(backlog_case_count)
-             FROM monthly_ops 
-             WHERE office_id = ?1
-               AND (year * 100 + month) BETWEEN (?2 * 100 + ?3) AND (?4 * 100 + ?5)"
-        };
-        
-        let (backlog_count, has_operations) = if is_single_month {
-            match conn.query_row(
-                operations_query,
-                params![office_id, start_year, start_month],
-                |row| row.get::<_, Option<i32>>(0),
-            ) {
-                Ok(Some(count)) => (Some(count), true),
-                Ok(None) => (None, false),
-                Err(_) => (None, false),
-            }
-        } else {
-            match conn.query_row(
-                operations_query,
-                params![office_id, start_year, start_month, end_year, end_month],
-                |row| row.get::<_, Option<f64>>(0),
-            ) {
-                Ok(Some(avg)) => (Some(avg.round() as i32), true),
-                Ok(None) => (None, false),
-                Err(_) => (None, false),
-            }
-        };
-        
-        // Check for volume data in date range
-        let has_volume = conn.query_row(
-             "SELECT 1 FROM monthly_volume
-             WHERE office_id = ?1
-               AND (year * 100 + month) BETWEEN (?2 * 100 + ?3) AND (?4 * 100 + ?5)
-             LIMIT 1",
-            params![office_id, start_year, start_month, end_year, end_month],
-            |_row| Ok(true),
-        ).unwrap_or(false);
-        
-        // Check for notes in date range
-        let has_notes = conn.query_row(
-             "SELECT 1 FROM notes_actions
-             WHERE office_id = ?1
-               AND (year * 100 + month) BETWEEN (?2 * 100 + ?3) AND (?4 * 100 + ?5)
-             LIMIT 1",
-            params![office_id, start_year, start_month, end_year, end_month],
-            |_row| Ok(true),
-        ).unwrap_or(false);
-        
-        // Determine latest month with any data (across all time, not just range)
-        let latest_data = conn.query_row(
-            "SELECT year, month FROM (
-                SELECT year, month FROM monthly_financials WHERE office_id = ?1
-                UNION
-                SELECT year, month FROM monthly_ops WHERE office_id = ?1
-                UNION
-                SELECT year, month FROM monthly_volume WHERE office_id = ?1
-             ) ORDER BY year DESC, month DESC LIMIT 1",
-            params![office_id],
-            |row| Ok((row.get::<_, i32>(0)?, row.get::<_, i32>(1)?)),
-        );
+        let backlog_count = backlogs.get(&office_id).copied().flatten();
+        let has_operations = backlog_count.is_some();
         
-        let (latest_year, latest_month) = match latest_data {
-            Ok((y, m)) => (Some(y), Some(m)),
-            Err(_) => (None, None),
+        let (latest_year, latest_month) = match latest.get(&office_id) {
+            Some(&(y, m)) => (Some(y), Some(m)),
+            None => (None, None),
         };
         
         summaries.push(OfficeSummary {
@@ -1046,64 +1465,127 @@ pub fn get_dashboard_data(
             backlog_count,
             has_financial,
             has_operations,
-            has_volume,
-            has_notes,
+            has_volume: with_volume.contains(&office_id),
+            has_notes: with_notes.contains(&office_id),
         });
     }
     
     Ok(summaries)
 }
 
+// Abort a bulk financial import when more than this percent of its office IDs are unknown
+const IMPORT_MAX_UNKNOWN_OFFICES_KEY: &str = "import_max_unknown_offices_percent";
+const DEFAULT_IMPORT_MAX_UNKNOWN_OFFICES_PERCENT: f64 = 50.0;
+
 // Bulk import financial data from Excel
 #[tauri::command]
 pub fn import_bulk_financials(
     db: State<DbConnection>,
     file_path: String,
-) -> Result<ImportSummary, String> {
-    use calamine::{open_workbook, Reader, Xlsx, Data};
+) -> Result<ImportSummary, LabPulseError> {
+    let conn = db.0.get()?;
+    bulk_financials_import(&conn, &file_path, false)
+}
+
+// Parse and validate a bulk financial file without writing anything, reporting the
+// rows that would be inserted or updated and every warning a real import would raise
+#[tauri::command]
+pub fn preview_bulk_financials(
+    db: State<DbConnection>,
+    file_path: String,
+) -> Result<ImportSummary, LabPulseError> {
+    let conn = db.0.get()?;
+    bulk_financials_import(&conn, &file_path, true)
+}
+
+// Fail a bulk import when too many of the sheet's office IDs (first column) are
+// missing from the offices table; the threshold comes from settings
+fn check_unknown_offices(
+    conn: &Connection,
+    sheet: &calamine::Range<calamine::Data>,
+) -> Result<(), LabPulseError> {
+    let file_office_ids: std::collections::HashSet<i64> = sheet
+        .rows()
+        .skip(1)
+        .filter_map(|row| row.first().and_then(cell_as_i64))
+        .collect();
+    
+    if !file_office_ids.is_empty() {
+        let mut unknown_ids = Vec::new();
+        for office_id in &file_office_ids {
+            let known: bool = conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM offices WHERE office_id = ?1)",
+                params![office_id],
+                |row| row.get(0),
+            )?;
+            if !known {
+                unknown_ids.push(*office_id);
+            }
+        }
+        
+        let unknown_percent = unknown_ids.len() as f64 / file_office_ids.len() as f64 * 100.0;
+        let max_unknown_percent = setting_f64(
+            conn,
+            IMPORT_MAX_UNKNOWN_OFFICES_KEY,
+            DEFAULT_IMPORT_MAX_UNKNOWN_OFFICES_PERCENT,
+        );
+        
+        if unknown_percent > max_unknown_percent {
+            unknown_ids.sort();
+            return Err(LabPulseError::Import(format!(
+                "{} of {} office IDs in this file ({:.0}%) are not in the offices table (e.g. {}). \
+                 Import the offices file first.",
+                unknown_ids.len(),
+                file_office_ids.len(),
+                unknown_percent,
+                unknown_ids.iter().take(5).map(|id| id.to_string()).collect::<Vec<_>>().join(", ")
+            )));
+        }
+    }
     
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn bulk_financials_import(
+    conn: &Connection,
+    file_path: &str,
+    preview: bool,
+) -> Result<ImportSummary, LabPulseError> {
+    use calamine::{open_workbook, Reader, Xlsx};
     
     // Open the Excel file
-    let mut workbook: Xlsx<_> = open_workbook(&file_path)
-        .map_err(|e| format!("Failed to open Excel file: {}", e))?;
+    let mut workbook: Xlsx<_> = open_workbook(file_path)
+        .map_err(|e| LabPulseError::Import(format!("Failed to open Excel file: {}", e)))?;
     
     // Get the monthly_financials sheet
     let sheet = workbook
         .worksheet_range("monthly_financials")
-        .map_err(|e| format!("Failed to read sheet 'monthly_financials': {}", e))?;
+        .map_err(|e| LabPulseError::Import(format!("Failed to read sheet 'monthly_financials': {}", e)))?;
     
     let mut rows_processed = 0;
     let mut rows_inserted = 0;
     let mut rows_updated = 0;
     let mut warnings = Vec::new();
     
-    // Helper function to get i64 from cell
-    fn get_i64(cell: &Data) -> Option<i64> {
-        match cell {
-            Data::Int(i) => Some(*i),
-            Data::Float(f) => Some(*f as i64),
-            Data::String(s) => s.trim().parse::<i64>().ok(),
-            _ => None,
-        }
+    let content_hash = file_content_hash(file_path);
+    if let Some(warning) = duplicate_import_warning(conn, content_hash.as_deref()) {
+        warnings.push(warning);
     }
     
-    // Helper function to get f64 from cell
-    fn get_f64(cell: &Data) -> Option<f64> {
-        match cell {
-            Data::Int(i) => Some(*i as f64),
-            Data::Float(f) => Some(*f),
-            Data::String(s) => s.trim().parse::<f64>().ok(),
-            _ => None,
-        }
-    }
+    // Pre-scan office IDs so a file for offices we don't know about fails fast
+    // instead of skipping most rows on foreign key errors
+    check_unknown_offices(conn, &sheet)?;
+    
+    // One transaction for all rows: a failed row is recorded as a warning without
+    // aborting the rest, and a crash mid-file leaves the database untouched
+    let tx = conn.unchecked_transaction()?;
     
     // Skip header row, start from row 1
     for (idx, row) in sheet.rows().enumerate().skip(1) {
         rows_processed += 1;
         
         // Parse row data
-        let office_id = match row.get(0).and_then(|v| get_i64(v)) {
+        let office_id = match row.first().and_then(cell_as_i64) {
             Some(id) => id,
             None => {
                 warnings.push(format!("Row {}: Missing or invalid office_id", idx + 2));
@@ -1111,7 +1593,7 @@ pub fn import_bulk_financials(
             }
         };
         
-        let year = match row.get(1).and_then(|v| get_i64(v)) {
+        let year = match row.get(1).and_then(cell_as_i64) {
             Some(y) => y as i32,
             None => {
                 warnings.push(format!("Row {}: Missing or invalid year", idx + 2));
@@ -1119,7 +1601,7 @@ pub fn import_bulk_financials(
             }
         };
         
-        let month = match row.get(2).and_then(|v| get_i64(v)) {
+        let month = match row.get(2).and_then(cell_as_i64) {
             Some(m) => m as i32,
             None => {
                 warnings.push(format!("Row {}: Missing or invalid month", idx + 2));
@@ -1128,36 +1610,59 @@ pub fn import_bulk_financials(
         };
         
         // Validate month range
-        if month < 1 || month > 12 {
-            warnings.push(format!("Row {}: Invalid month {} (must be 1-12)", idx + 2, month));
+        if let Err(e) = validate_month(month) {
+            warnings.push(format!("Row {}: {}", idx + 2, e));
             continue;
         }
         
         // Parse financial fields (allow 0 or NULL)
-        let revenue = row.get(3).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let lab_exp_no_outside = row.get(4).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let lab_exp_with_outside = row.get(5).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let teeth_supplies = row.get(6).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let lab_supplies = row.get(7).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let lab_hub = row.get(8).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let lss_expense = row.get(9).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let personnel_exp = row.get(10).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let overtime_exp = row.get(11).and_then(|v| get_f64(v)).unwrap_or(0.0);
-        let bonus_exp = row.get(12).and_then(|v| get_f64(v)).unwrap_or(0.0);
+        let revenue = row.get(3).and_then(cell_as_f64).unwrap_or(0.0);
+        let lab_exp_no_outside = row.get(4).and_then(cell_as_f64).unwrap_or(0.0);
+        let lab_exp_with_outside = row.get(5).and_then(cell_as_f64).unwrap_or(0.0);
+        let teeth_supplies = row.get(6).and_then(cell_as_f64).unwrap_or(0.0);
+        let lab_supplies = row.get(7).and_then(cell_as_f64).unwrap_or(0.0);
+        let lab_hub = row.get(8).and_then(cell_as_f64).unwrap_or(0.0);
+        let lss_expense = row.get(9).and_then(cell_as_f64).unwrap_or(0.0);
+        let personnel_exp = row.get(10).and_then(cell_as_f64).unwrap_or(0.0);
+        let overtime_exp = row.get(11).and_then(cell_as_f64).unwrap_or(0.0);
+        let bonus_exp = row.get(12).and_then(cell_as_f64).unwrap_or(0.0);
         // Note: column 13 (outside_lab_spend) is ignored - LabPulse auto-calculates this
         
+        if let Err(e) = validate_lab_expenses(lab_exp_no_outside, lab_exp_with_outside) {
+            warnings.push(format!("Row {}: {}", idx + 2, e));
+            continue;
+        }
+        
         // Check if record exists
-        let exists = conn.query_row(
+        let exists = tx.query_row(
             "SELECT COUNT(*) FROM monthly_financials WHERE office_id = ?1 AND year = ?2 AND month = ?3",
             params![office_id, year, month],
             |row| row.get::<_, i64>(0),
         ).unwrap_or(0) > 0;
         
+        // Preview stops short of writing; rows the office foreign key would reject
+        // are reported the same way a failed insert would be
+        if preview {
+            let office_known: bool = tx.query_row(
+                "SELECT EXISTS(SELECT 1 FROM offices WHERE office_id = ?1)",
+                params![office_id],
+                |row| row.get(0),
+            )?;
+            if !office_known {
+                warnings.push(format!("Row {}: Failed to import - office {} not found", idx + 2, office_id));
+            } else if exists {
+                rows_updated += 1;
+            } else {
+                rows_inserted += 1;
+            }
+            continue;
+        }
+        
         // Calculate outside_lab_spend (auto-calculated)
         let outside_lab_spend = lab_exp_with_outside - lab_exp_no_outside;
         
         // Insert or update
-        let result = conn.execute(
+        let result = tx.execute(
             "INSERT INTO monthly_financials (
                 office_id, year, month, revenue, lab_exp_no_outside, lab_exp_with_outside,
                 outside_lab_spend, teeth_supplies, lab_supplies, lab_hub, lss_expense, 
@@ -1197,20 +1702,26 @@ pub fn import_bulk_financials(
         }
     }
     
-    // Log import
-    conn.execute(
-        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings) VALUES ('bulk_financials', ?1, ?2, ?3, ?4, ?5)",
-        params![
-            file_path,
-            rows_processed,
-            rows_inserted,
-            rows_updated,
-            serde_json::to_string(&warnings).unwrap_or_default()
-        ],
-    ).ok(); // Don't fail if logging fails
+    // A preview writes nothing, not even an import log entry
+    if !preview {
+        // Log import
+        tx.execute(
+            "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings, content_hash) VALUES ('bulk_financials', ?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                file_path,
+                rows_processed,
+                rows_inserted,
+                rows_updated,
+                serde_json::to_string(&warnings).unwrap_or_default(),
+                content_hash
+            ],
+        ).ok(); // Don't fail if logging fails
+        
+        tx.commit()?;
+    }
     
     Ok(ImportSummary {
-        filename: file_path.split('\\').last().or_else(|| file_path.split('/').last()).unwrap_or(&file_path).to_string(),
+        filename: file_path.split('\\').next_back().or_else(|| file_path.split('/').next_back()).unwrap_or(file_path).to_string(),
         rows_processed,
         rows_inserted,
         rows_updated,
@@ -1218,235 +1729,563 @@ pub fn import_bulk_financials(
     })
 }
 
-// Bulk import weekly volume data from Excel
+// Bulk import monthly targets from Excel: office_id, year, month, metric, target_value
 #[tauri::command]
-pub fn import_bulk_weekly_volume(
+pub fn import_bulk_targets(
     db: State<DbConnection>,
     file_path: String,
-) -> Result<ImportSummary, String> {
-    use calamine::{open_workbook, Reader, Xlsx, Data};
+) -> Result<ImportSummary, LabPulseError> {
+    use calamine::{open_workbook, Reader, Xlsx};
     
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.0.get()?;
     
-    // Open the Excel file
     let mut workbook: Xlsx<_> = open_workbook(&file_path)
-        .map_err(|e| format!("Failed to open Excel file: {}", e))?;
+        .map_err(|e| LabPulseError::Import(format!("Failed to open Excel file: {}", e)))?;
     
-    // Get the first sheet (Sheet1)
-    let sheet = workbook
-        .worksheet_range_at(0)
-        .ok_or("No worksheets found in file")?
-        .map_err(|e| format!("Failed to read sheet: {}", e))?;
+    let sheet = match workbook.worksheet_range_at(0) {
+        Some(Ok(range)) => range,
+        Some(Err(e)) => return Err(LabPulseError::Import(format!("Failed to read first sheet: {}", e))),
+        None => return Err(LabPulseError::Import("Workbook has no sheets".to_string())),
+    };
     
     let mut rows_processed = 0;
-    let mut weekly_inserted = 0;
-    let mut weekly_skipped = 0;
-    let mut monthly_updated = 0;
+    let mut rows_inserted = 0;
+    let mut rows_updated = 0;
     let mut warnings = Vec::new();
     
-    // Helper function to get integer from cell
-    let get_i64 = |data: &Data| -> Option<i64> {
-        match data {
-            Data::Int(i) => Some(*i),
-            Data::Float(f) => Some(*f as i64),
-            Data::String(s) => s.parse::<i64>().ok(),
-            Data::Bool(b) => Some(if *b { 1 } else { 0 }),
-            _ => None,
-        }
-    };
+    let content_hash = file_content_hash(&file_path);
+    if let Some(warning) = duplicate_import_warning(&conn, content_hash.as_deref()) {
+        warnings.push(warning);
+    }
     
-    // Skip header row (row 0), start from row 1
+    let known_offices: std::collections::HashSet<i64> = conn
+        .prepare("SELECT office_id FROM offices")?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    
+    let tx = conn.unchecked_transaction()?;
+    
+    // Skip header row, start from row 1
     for (idx, row) in sheet.rows().enumerate().skip(1) {
         rows_processed += 1;
         
-        // Parse row data based on column positions
-        // Processed format: Column 0: office_id, Column 1: year, Column 2: month, Column 3: week_number
-        let office_id = match row.get(0).and_then(get_i64) {
+        let office_id = match row.first().and_then(cell_as_i64) {
             Some(id) => id,
             None => {
-                warnings.push(format!("Row {}: Missing or invalid office ID", idx + 1));
+                warnings.push(format!("Row {}: Missing or invalid office_id", idx + 2));
                 continue;
             }
         };
-
-        let year = match row.get(1).and_then(get_i64) {
-            Some(y) => y as i32,
-            None => {
-                warnings.push(format!("Row {}: Missing or invalid year", idx + 1));
+        
+        if !known_offices.contains(&office_id) {
+            warnings.push(format!("Row {}: Office {} not found", idx + 2, office_id));
+            continue;
+        }
+        
+        let (year, month) = match (row.get(1).and_then(cell_as_i64), row.get(2).and_then(cell_as_i64)) {
+            (Some(y), Some(m)) => (y as i32, m as i32),
+            _ => {
+                warnings.push(format!("Row {}: Missing or invalid year/month", idx + 2));
                 continue;
             }
         };
-
-        // Month is in column 2 but we'll calculate it from week_number, so just read week_number
-        let week_number = match row.get(3).and_then(get_i64) {
-            Some(w) => w as i32,
+        
+        if let Err(e) = validate_month(month) {
+            warnings.push(format!("Row {}: {}", idx + 2, e));
+            continue;
+        }
+        
+        let metric = row.get(3).map(|c| cell_as_string(c).to_lowercase()).unwrap_or_default();
+        if let Err(e) = validate_metric(&metric) {
+            warnings.push(format!("Row {}: {}", idx + 2, e));
+            continue;
+        }
+        
+        let target_value = match row.get(4).and_then(cell_as_f64) {
+            Some(v) => v,
             None => {
-                warnings.push(format!("Row {}: Missing or invalid week number", idx + 1));
+                warnings.push(format!("Row {}: Missing or invalid target_value", idx + 2));
                 continue;
             }
         };
-
-        if week_number < 1 || week_number > 53 {
-            warnings.push(format!("Row {}: Invalid week number {} (must be 1-53)", idx + 1, week_number));
-            continue;
-        }
         
-        // Parse all volume fields - processed file starts at column 6
-        let lab_setups = row.get(6).and_then(get_i64).unwrap_or(0) as i32;
-        let lab_fixed_cases = row.get(7).and_then(get_i64).unwrap_or(0) as i32;
-        let lab_over_denture = row.get(8).and_then(get_i64).unwrap_or(0) as i32;
-        let lab_processes = row.get(9).and_then(get_i64).unwrap_or(0) as i32;
-        let lab_finishes = row.get(10).and_then(get_i64).unwrap_or(0) as i32;
-        
-        let clinic_wax_tryin = row.get(11).and_then(get_i64).unwrap_or(0) as i32;
-        let clinic_delivery = row.get(12).and_then(get_i64).unwrap_or(0) as i32;
-        let clinic_outside_lab = row.get(13).and_then(get_i64).unwrap_or(0) as i32;
-        let clinic_on_hold = row.get(14).and_then(get_i64).unwrap_or(0) as i32;
-        
-        let immediate_units = row.get(15).and_then(get_i64).unwrap_or(0) as i32;
-        let economy_units = row.get(16).and_then(get_i64).unwrap_or(0) as i32;
-        let economy_plus_units = row.get(17).and_then(get_i64).unwrap_or(0) as i32;
-        let premium_units = row.get(18).and_then(get_i64).unwrap_or(0) as i32;
-        let ultimate_units = row.get(19).and_then(get_i64).unwrap_or(0) as i32;
-        let repair_units = row.get(20).and_then(get_i64).unwrap_or(0) as i32;
-        let reline_units = row.get(21).and_then(get_i64).unwrap_or(0) as i32;
-        let partial_units = row.get(22).and_then(get_i64).unwrap_or(0) as i32;
-        let retry_units = row.get(23).and_then(get_i64).unwrap_or(0) as i32;
-        let remake_units = row.get(24).and_then(get_i64).unwrap_or(0) as i32;
-        let bite_block_units = row.get(25).and_then(get_i64).unwrap_or(0) as i32;
+        let exists: bool = tx.query_row(
+            "SELECT EXISTS(SELECT 1 FROM monthly_targets
+             WHERE office_id = ?1 AND year = ?2 AND month = ?3 AND metric = ?4)",
+            params![office_id, year, month, metric],
+            |row| row.get(0),
+        )?;
         
-        // Check if weekly record already exists
-        let exists = conn.query_row(
-            "SELECT COUNT(*) FROM weekly_volume WHERE office_id = ?1 AND year = ?2 AND week_number = ?3",
-            params![office_id, year, week_number],
-            |row| row.get::<_, i64>(0),
-        ).unwrap_or(0) > 0;
+        tx.execute(
+            "INSERT INTO monthly_targets (office_id, year, month, metric, target_value)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(office_id, year, month, metric) DO UPDATE SET
+                target_value = excluded.target_value,
+                updated_at = CURRENT_TIMESTAMP",
+            params![office_id, year, month, metric, target_value],
+        )?;
         
         if exists {
-            weekly_skipped += 1;
-            continue; // Skip duplicate weeks
-        }
-        
-        // Insert weekly record
-        let result = conn.execute(
-            "INSERT INTO weekly_volume (
-                office_id, year, week_number,
-                lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
-                clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
-                immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
-                repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23)",
-            params![
-                office_id, year, week_number,
-                lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
-                clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
-                immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
-                repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units
-            ],
-        );
-        
-        match result {
-            Ok(_) => weekly_inserted += 1,
-            Err(e) => {
-                warnings.push(format!("Row {}: Failed to insert weekly record - {}", idx + 1, e));
-                continue;
-            }
+            rows_updated += 1;
+        } else {
+            rows_inserted += 1;
         }
     }
     
-    // After importing weekly data, aggregate to monthly
-    // This recalculates monthly_volume from all weekly records
-    monthly_updated = aggregate_weekly_to_monthly(&conn)?;
-    
-    // Log the import
-    conn.execute(
-        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated)
-         VALUES ('weekly_volume', ?1, ?2, ?3, ?4)",
+    tx.execute(
+        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings, content_hash)
+         VALUES ('bulk_targets', ?1, ?2, ?3, ?4, ?5, ?6)",
         params![
-            file_path.split('\\').last().or_else(|| file_path.split('/').last()).unwrap_or(&file_path),
+            file_path,
             rows_processed,
-            weekly_inserted,
-            monthly_updated
+            rows_inserted,
+            rows_updated,
+            serde_json::to_string(&warnings).unwrap_or_default(),
+            content_hash
         ],
-    ).map_err(|e| format!("Failed to log import: {}", e))?;
+    )?;
+    
+    tx.commit()?;
     
     Ok(ImportSummary {
-        filename: file_path.split('\\').last().or_else(|| file_path.split('/').last()).unwrap_or(&file_path).to_string(),
+        filename: file_path.split('\\').next_back().or_else(|| file_path.split('/').next_back()).unwrap_or(&file_path).to_string(),
         rows_processed,
-        rows_inserted: weekly_inserted,
-        rows_updated: monthly_updated as usize,
+        rows_inserted,
+        rows_updated,
         warnings,
     })
 }
 
-// Helper function to aggregate weekly data to monthly
-fn aggregate_weekly_to_monthly(conn: &Connection) -> Result<i32, String> {
-    // Get all unique office/year/month combinations from weekly data
+// One office's target for a metric in a month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthlyTarget {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+    pub metric: String,
+    pub target_value: f64,
+}
+
+// Get an office's imported targets for a year, by month then metric
+#[tauri::command]
+pub fn get_targets(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+) -> Result<Vec<MonthlyTarget>, LabPulseError> {
+    let conn = db.0.get()?;
+    
     let mut stmt = conn.prepare(
-        "SELECT DISTINCT office_id, year,
-                CASE 
-                    WHEN week_number <= 4 THEN 1
-                    WHEN week_number <= 8 THEN 2
-                    WHEN week_number <= 13 THEN 3
-                    WHEN week_number <= 17 THEN 4
-                    WHEN week_number <= 22 THEN 5
-                    WHEN week_number <= 26 THEN 6
-                    WHEN week_number <= 30 THEN 7
-                    WHEN week_number <= 35 THEN 8
-                    WHEN week_number <= 39 THEN 9
-                    WHEN week_number <= 43 THEN 10
-                    WHEN week_number <= 48 THEN 11
-                    ELSE 12
-                END as month
-         FROM weekly_volume
-         ORDER BY office_id, year, month"
-    ).map_err(|e| e.to_string())?;
+        "SELECT office_id, year, month, metric, target_value
+         FROM monthly_targets
+         WHERE office_id = ?1 AND year = ?2
+         ORDER BY month, metric"
+    )?;
+    
+    let targets = stmt.query_map(params![office_id, year], |row| {
+        Ok(MonthlyTarget {
+            office_id: row.get(0)?,
+            year: row.get(1)?,
+            month: row.get(2)?,
+            metric: row.get(3)?,
+            target_value: row.get(4)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
     
-    let office_months: Vec<(i64, i32, i32)> = stmt
-        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
-        .map_err(|e| e.to_string())?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())?;
+    Ok(targets)
+}
+
+// Bulk import monthly operations data from Excel. Columns: office_id, year, month,
+// backlog_case_count, overtime_value, labor_model_value, current_staff,
+// required_staff, staffing_trend. Blank or missing value cells leave any stored
+// value alone, so older six-column files don't wipe staffing entered by hand.
+#[tauri::command]
+pub fn import_bulk_operations(
+    db: State<DbConnection>,
+    file_path: String,
+) -> Result<ImportSummary, LabPulseError> {
+    use calamine::{open_workbook, Reader, Xlsx};
     
-    let mut updated = 0;
+    let conn = db.0.get()?;
     
-    for (office_id, year, month) in office_months {
-        // Calculate week range for this month
-        let (week_start, week_end) = match month {
-            1 => (1, 4), 2 => (5, 8), 3 => (9, 13), 4 => (14, 17),
-            5 => (18, 22), 6 => (23, 26), 7 => (27, 30), 8 => (31, 35),
-            9 => (36, 39), 10 => (40, 43), 11 => (44, 48), 12 => (49, 53),
-            _ => continue,
+    let mut workbook: Xlsx<_> = open_workbook(&file_path)
+        .map_err(|e| LabPulseError::Import(format!("Failed to open Excel file: {}", e)))?;
+    
+    // Prefer a sheet named like the table, otherwise take the first sheet
+    let sheet = match workbook.worksheet_range("monthly_ops") {
+        Ok(range) => range,
+        Err(_) => workbook
+            .worksheet_range_at(0)
+            .ok_or_else(|| LabPulseError::Import("No worksheets found in file".to_string()))?
+            .map_err(|e| LabPulseError::Import(format!("Failed to read sheet: {}", e)))?,
+    };
+    
+    let mut rows_processed = 0;
+    let mut rows_inserted = 0;
+    let mut rows_updated = 0;
+    let mut warnings = Vec::new();
+    
+    let content_hash = file_content_hash(&file_path);
+    if let Some(warning) = duplicate_import_warning(&conn, content_hash.as_deref()) {
+        warnings.push(warning);
+    }
+    
+    check_unknown_offices(&conn, &sheet)?;
+    
+    let tx = conn.unchecked_transaction()?;
+    
+    // Skip header row, start from row 1
+    for (idx, row) in sheet.rows().enumerate().skip(1) {
+        rows_processed += 1;
+        
+        let office_id = match row.first().and_then(cell_as_i64) {
+            Some(id) => id,
+            None => {
+                warnings.push(format!("Row {}: Missing or invalid office_id", idx + 2));
+                continue;
+            }
         };
         
-        // Average all weekly records for this month
-        let monthly_data = conn.query_row(
-            "SELECT 
-                COALESCE(AVG(lab_setups), 0), COALESCE(AVG(lab_fixed_cases), 0), COALESCE(AVG(lab_over_denture), 0), 
-                COALESCE(AVG(lab_processes), 0), COALESCE(AVG(lab_finishes), 0),
-                COALESCE(AVG(clinic_wax_tryin), 0), COALESCE(AVG(clinic_delivery), 0), COALESCE(AVG(clinic_outside_lab), 0), COALESCE(AVG(clinic_on_hold), 0),
-                COALESCE(AVG(immediate_units), 0), COALESCE(AVG(economy_units), 0), COALESCE(AVG(economy_plus_units), 0), 
-                COALESCE(AVG(premium_units), 0), COALESCE(AVG(ultimate_units), 0), COALESCE(AVG(repair_units), 0), 
-                COALESCE(AVG(reline_units), 0), COALESCE(AVG(partial_units), 0), COALESCE(AVG(retry_units), 0), 
-                COALESCE(AVG(remake_units), 0), COALESCE(AVG(bite_block_units), 0)
-             FROM weekly_volume
-             WHERE office_id = ?1 AND year = ?2 AND week_number BETWEEN ?3 AND ?4",
-            params![office_id, year, week_start, week_end],
-            |row| {
-                Ok((
-                    row.get::<_, f64>(0)?.round() as i32, row.get::<_, f64>(1)?.round() as i32, row.get::<_, f64>(2)?.round() as i32,
-                    row.get::<_, f64>(3)?.round() as i32, row.get::<_, f64>(4)?.round() as i32, row.get::<_, f64>(5)?.round() as i32,
-                    row.get::<_, f64>(6)?.round() as i32, row.get::<_, f64>(7)?.round() as i32, row.get::<_, f64>(8)?.round() as i32,
-                    row.get::<_, f64>(9)?.round() as i32, row.get::<_, f64>(10)?.round() as i32, row.get::<_, f64>(11)?.round() as i32,
-                    row.get::<_, f64>(12)?.round() as i32, row.get::<_, f64>(13)?.round() as i32, row.get::<_, f64>(14)?.round() as i32,
-                    row.get::<_, f64>(15)?.round() as i32, row.get::<_, f64>(16)?.round() as i32, row.get::<_, f64>(17)?.round() as i32,
-                    row.get::<_, f64>(18)?.round() as i32, row.get::<_, f64>(19)?.round() as i32,
-                ))
-            },
-        ).map_err(|e| e.to_string())?;
+        let (year, month) = match (row.get(1).and_then(cell_as_i64), row.get(2).and_then(cell_as_i64)) {
+            (Some(y), Some(m)) => (y as i32, m as i32),
+            _ => {
+                warnings.push(format!("Row {}: Missing or invalid year/month", idx + 2));
+                continue;
+            }
+        };
         
-        let (lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
-             clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
+        if let Err(e) = validate_month(month) {
+            warnings.push(format!("Row {}: {}", idx + 2, e));
+            continue;
+        }
+        
+        let backlog_case_count = row.get(3).and_then(cell_as_i64);
+        let overtime_value = row.get(4).and_then(cell_as_f64);
+        let labor_model_value = row.get(5).and_then(cell_as_f64);
+        let current_staff = row.get(6).and_then(cell_as_f64);
+        let required_staff = row.get(7).and_then(cell_as_f64);
+        let staffing_trend = row.get(8).and_then(cell_as_f64);
+        
+        let exists: bool = tx.query_row(
+            "SELECT EXISTS(SELECT 1 FROM monthly_ops WHERE office_id = ?1 AND year = ?2 AND month = ?3)",
+            params![office_id, year, month],
+            |row| row.get(0),
+        )?;
+        
+        let result = tx.execute(
+            "INSERT INTO monthly_ops (
+                office_id, year, month, backlog_case_count, overtime_value, labor_model_value,
+                current_staff, required_staff, staffing_trend
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            ON CONFLICT(office_id, year, month) DO UPDATE SET
+                backlog_case_count = COALESCE(excluded.backlog_case_count, monthly_ops.backlog_case_count),
+                overtime_value = COALESCE(excluded.overtime_value, monthly_ops.overtime_value),
+                labor_model_value = COALESCE(excluded.labor_model_value, monthly_ops.labor_model_value),
+                current_staff = COALESCE(excluded.current_staff, monthly_ops.current_staff),
+                required_staff = COALESCE(excluded.required_staff, monthly_ops.required_staff),
+                staffing_trend = COALESCE(excluded.staffing_trend, monthly_ops.staffing_trend),
+                updated_at = CURRENT_TIMESTAMP",
+            params![
+                office_id, year, month, backlog_case_count, overtime_value, labor_model_value,
+                current_staff, required_staff, staffing_trend
+            ],
+        );
+        
+        match result {
+            Ok(_) => {
+                if exists {
+                    rows_updated += 1;
+                } else {
+                    rows_inserted += 1;
+                }
+            }
+            Err(e) => {
+                warnings.push(format!("Row {}: Failed to import - {}", idx + 2, e));
+            }
+        }
+    }
+    
+    tx.execute(
+        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings, content_hash)
+         VALUES ('bulk_operations', ?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            file_path,
+            rows_processed,
+            rows_inserted,
+            rows_updated,
+            serde_json::to_string(&warnings).unwrap_or_default(),
+            content_hash
+        ],
+    )?;
+    
+    tx.commit()?;
+    
+    Ok(ImportSummary {
+        filename: file_path.split('\\').next_back().or_else(|| file_path.split('/').next_back()).unwrap_or(&file_path).to_string(),
+        rows_processed,
+        rows_inserted,
+        rows_updated,
+        warnings,
+    })
+}
+
+// Bulk import weekly volume data from Excel. Weeks already stored are skipped
+// unless `overwrite` is set, in which case the file's numbers replace them.
+#[tauri::command]
+pub fn import_bulk_weekly_volume(
+    db: State<DbConnection>,
+    file_path: String,
+    overwrite: bool,
+) -> Result<ImportSummary, LabPulseError> {
+    use calamine::{open_workbook, Reader, Xlsx};
+    
+    let conn = db.0.get()?;
+    
+    // Open the Excel file
+    let mut workbook: Xlsx<_> = open_workbook(&file_path)
+        .map_err(|e| LabPulseError::Import(format!("Failed to open Excel file: {}", e)))?;
+    
+    // Get the first sheet (Sheet1)
+    let sheet = workbook
+        .worksheet_range_at(0)
+        .ok_or_else(|| LabPulseError::Import("No worksheets found in file".to_string()))?
+        .map_err(|e| LabPulseError::Import(format!("Failed to read sheet: {}", e)))?;
+    
+    let mut rows_processed = 0;
+    let mut weekly_inserted = 0;
+    let mut weekly_updated = 0;
+    let mut weekly_skipped = 0;
+    let mut touched_months = std::collections::BTreeSet::new();
+    let mut warnings = Vec::new();
+    
+    let content_hash = file_content_hash(&file_path);
+    if let Some(warning) = duplicate_import_warning(&conn, content_hash.as_deref()) {
+        warnings.push(warning);
+    }
+    
+    // One transaction for all rows and the monthly rollup that follows
+    let tx = conn.unchecked_transaction()?;
+    
+    // Skip header row (row 0), start from row 1
+    for (idx, row) in sheet.rows().enumerate().skip(1) {
+        rows_processed += 1;
+        
+        // Parse row data based on column positions
+        // Processed format: Column 0: office_id, Column 1: year, Column 2: month, Column 3: week_number
+        let office_id = match row.first().and_then(cell_as_i64) {
+            Some(id) => id,
+            None => {
+                warnings.push(format!("Row {}: Missing or invalid office ID", idx + 1));
+                continue;
+            }
+        };
+
+        let year = match row.get(1).and_then(cell_as_i64) {
+            Some(y) => y as i32,
+            None => {
+                warnings.push(format!("Row {}: Missing or invalid year", idx + 1));
+                continue;
+            }
+        };
+
+        // Month is in column 2 but we'll calculate it from week_number, so just read week_number
+        let week_number = match row.get(3).and_then(cell_as_i64) {
+            Some(w) => w as i32,
+            None => {
+                warnings.push(format!("Row {}: Missing or invalid week number", idx + 1));
+                continue;
+            }
+        };
+
+        if !(1..=53).contains(&week_number) {
+            warnings.push(format!("Row {}: Invalid week number {} (must be 1-53)", idx + 1, week_number));
+            continue;
+        }
+        
+        // Parse all volume fields - processed file starts at column 6
+        let lab_setups = row.get(6).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let lab_fixed_cases = row.get(7).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let lab_over_denture = row.get(8).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let lab_processes = row.get(9).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let lab_finishes = row.get(10).and_then(cell_as_i64).unwrap_or(0) as i32;
+        
+        let clinic_wax_tryin = row.get(11).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let clinic_delivery = row.get(12).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let clinic_outside_lab = row.get(13).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let clinic_on_hold = row.get(14).and_then(cell_as_i64).unwrap_or(0) as i32;
+        
+        let immediate_units = row.get(15).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let economy_units = row.get(16).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let economy_plus_units = row.get(17).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let premium_units = row.get(18).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let ultimate_units = row.get(19).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let repair_units = row.get(20).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let reline_units = row.get(21).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let partial_units = row.get(22).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let retry_units = row.get(23).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let remake_units = row.get(24).and_then(cell_as_i64).unwrap_or(0) as i32;
+        let bite_block_units = row.get(25).and_then(cell_as_i64).unwrap_or(0) as i32;
+        
+        // Check if weekly record already exists
+        let exists = tx.query_row(
+            "SELECT COUNT(*) FROM weekly_volume WHERE office_id = ?1 AND year = ?2 AND week_number = ?3",
+            params![office_id, year, week_number],
+            |row| row.get::<_, i64>(0),
+        ).unwrap_or(0) > 0;
+        
+        if exists && !overwrite {
+            weekly_skipped += 1;
+            continue; // Skip duplicate weeks
+        }
+        
+        // Insert weekly record, replacing the stored week when overwriting
+        let result = tx.execute(
+            "INSERT INTO weekly_volume (
+                office_id, year, week_number,
+                lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
+                clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
+                immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
+                repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23)
+            ON CONFLICT(office_id, year, week_number) DO UPDATE SET
+                lab_setups = excluded.lab_setups,
+                lab_fixed_cases = excluded.lab_fixed_cases,
+                lab_over_denture = excluded.lab_over_denture,
+                lab_processes = excluded.lab_processes,
+                lab_finishes = excluded.lab_finishes,
+                clinic_wax_tryin = excluded.clinic_wax_tryin,
+                clinic_delivery = excluded.clinic_delivery,
+                clinic_outside_lab = excluded.clinic_outside_lab,
+                clinic_on_hold = excluded.clinic_on_hold,
+                immediate_units = excluded.immediate_units,
+                economy_units = excluded.economy_units,
+                economy_plus_units = excluded.economy_plus_units,
+                premium_units = excluded.premium_units,
+                ultimate_units = excluded.ultimate_units,
+                repair_units = excluded.repair_units,
+                reline_units = excluded.reline_units,
+                partial_units = excluded.partial_units,
+                retry_units = excluded.retry_units,
+                remake_units = excluded.remake_units,
+                bite_block_units = excluded.bite_block_units",
+            params![
+                office_id, year, week_number,
+                lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
+                clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
+                immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
+                repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units
+            ],
+        );
+        
+        match result {
+            Ok(_) => {
+                if exists {
+                    weekly_updated += 1;
+                } else {
+                    weekly_inserted += 1;
+                }
+                touched_months.insert((office_id, year, month_for_week(year, week_number)));
+            }
+            Err(e) => {
+                warnings.push(format!("Row {}: Failed to insert weekly record - {}", idx + 1, e));
+                continue;
+            }
+        }
+    }
+    
+    if weekly_skipped > 0 {
+        warnings.push(format!(
+            "{} week(s) already imported were skipped; import again with overwrite to replace them",
+            weekly_skipped
+        ));
+    }
+    
+    // After importing weekly data, rebuild only the monthly rows this file touched
+    aggregate_weekly_to_monthly(&tx, &touched_months)?;
+    
+    // Log the import
+    tx.execute(
+        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, content_hash)
+         VALUES ('weekly_volume', ?1, ?2, ?3, ?4, ?5)",
+        params![
+            file_path.split('\\').next_back().or_else(|| file_path.split('/').next_back()).unwrap_or(&file_path),
+            rows_processed,
+            weekly_inserted,
+            weekly_updated,
+            content_hash
+        ],
+    )?;
+    
+    tx.commit()?;
+    
+    Ok(ImportSummary {
+        filename: file_path.split('\\').next_back().or_else(|| file_path.split('/').next_back()).unwrap_or(&file_path).to_string(),
+        rows_processed,
+        rows_inserted: weekly_inserted,
+        rows_updated: weekly_updated,
+        warnings,
+    })
+}
+
+// Settings key for how weekly unit production rolls up to a month: "average"
+// (the default and original behavior) or "sum" for monthly totals. Backlog
+// columns are point-in-time counts and are always averaged.
+const WEEKLY_AGGREGATION_METHOD_KEY: &str = "weekly_aggregation_method";
+
+// Rebuild the monthly_volume rows for the given (office_id, year, month) keys
+// from their weekly records
+fn aggregate_weekly_to_monthly(
+    conn: &Connection,
+    office_months: &std::collections::BTreeSet<(i64, i32, i32)>,
+) -> Result<(), LabPulseError> {
+    let unit_aggregate = match conn.query_row(
+        "SELECT value FROM settings WHERE key = ?1",
+        params![WEEKLY_AGGREGATION_METHOD_KEY],
+        |row| row.get::<_, String>(0),
+    ) {
+        Ok(method) if method.trim().eq_ignore_ascii_case("sum") => "SUM",
+        _ => "AVG",
+    };
+    
+    for &(office_id, year, month) in office_months {
+        // Calculate week range for this month
+        let (week_start, week_end) = match month_week_range(year, month) {
+            Some(range) => range,
+            None => continue,
+        };
+        
+        // Roll the weekly records up: backlog columns averaged, units by unit_aggregate
+        let monthly_data = conn.query_row(
+            &format!(
+                "SELECT 
+                    COALESCE(AVG(lab_setups), 0), COALESCE(AVG(lab_fixed_cases), 0), COALESCE(AVG(lab_over_denture), 0), 
+                    COALESCE(AVG(lab_processes), 0), COALESCE(AVG(lab_finishes), 0),
+                    COALESCE(AVG(clinic_wax_tryin), 0), COALESCE(AVG(clinic_delivery), 0), COALESCE(AVG(clinic_outside_lab), 0), COALESCE(AVG(clinic_on_hold), 0),
+                    COALESCE({agg}(immediate_units), 0), COALESCE({agg}(economy_units), 0), COALESCE({agg}(economy_plus_units), 0), 
+                    COALESCE({agg}(premium_units), 0), COALESCE({agg}(ultimate_units), 0), COALESCE({agg}(repair_units), 0), 
+                    COALESCE({agg}(reline_units), 0), COALESCE({agg}(partial_units), 0), COALESCE({agg}(retry_units), 0), 
+                    COALESCE({agg}(remake_units), 0), COALESCE({agg}(bite_block_units), 0)
+                 FROM weekly_volume
+                 WHERE office_id = ?1 AND year = ?2 AND week_number BETWEEN ?3 AND ?4",
+                agg = unit_aggregate
+            ),
+            params![office_id, year, week_start, week_end],
+            |row| {
+                Ok((
+                    row.get::<_, f64>(0)?.round() as i32, row.get::<_, f64>(1)?.round() as i32, row.get::<_, f64>(2)?.round() as i32,
+                    row.get::<_, f64>(3)?.round() as i32, row.get::<_, f64>(4)?.round() as i32, row.get::<_, f64>(5)?.round() as i32,
+                    row.get::<_, f64>(6)?.round() as i32, row.get::<_, f64>(7)?.round() as i32, row.get::<_, f64>(8)?.round() as i32,
+                    row.get::<_, f64>(9)?.round() as i32, row.get::<_, f64>(10)?.round() as i32, row.get::<_, f64>(11)?.round() as i32,
+                    row.get::<_, f64>(12)?.round() as i32, row.get::<_, f64>(13)?.round() as i32, row.get::<_, f64>(14)?.round() as i32,
+                    row.get::<_, f64>(15)?.round() as i32, row.get::<_, f64>(16)?.round() as i32, row.get::<_, f64>(17)?.round() as i32,
+                    row.get::<_, f64>(18)?.round() as i32, row.get::<_, f64>(19)?.round() as i32,
+                ))
+            },
+        )?;
+        
+        let (lab_setups, lab_fixed_cases, lab_over_denture, lab_processes, lab_finishes,
+             clinic_wax_tryin, clinic_delivery, clinic_outside_lab, clinic_on_hold,
              immediate_units, economy_units, economy_plus_units, premium_units, ultimate_units,
              repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units) = monthly_data;
         
@@ -1499,12 +2338,35 @@ fn aggregate_weekly_to_monthly(conn: &Connection) -> Result<i32, String> {
                 repair_units, reline_units, partial_units, retry_units, remake_units, bite_block_units,
                 total_weekly_units
             ],
-        ).map_err(|e| e.to_string())?;
-        
-        updated += 1;
+        )?;
     }
     
-    Ok(updated)
+    Ok(())
+}
+
+// Rebuild every monthly_volume row that has weekly records, e.g. after changing
+// the weekly aggregation method. Imports only rebuild the months they touch.
+// Returns the number of office-months rebuilt.
+#[tauri::command]
+pub fn rebuild_all_aggregates(db: State<DbConnection>) -> Result<usize, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT office_id, year, week_number FROM weekly_volume"
+    )?;
+    
+    let office_months: std::collections::BTreeSet<(i64, i32, i32)> = stmt
+        .query_map([], |row| {
+            let year = row.get(1)?;
+            Ok((row.get(0)?, year, month_for_week(year, row.get(2)?)))
+        })?
+        .collect::<Result<_, _>>()?;
+    
+    let tx = conn.unchecked_transaction()?;
+    aggregate_weekly_to_monthly(&tx, &office_months)?;
+    tx.commit()?;
+    
+    Ok(office_months.len())
 }
 
 // Get rankings for offices based on metric and time period
@@ -1516,8 +2378,8 @@ pub fn get_office_rankings(
     start_month: i32,
     end_year: i32,
     end_month: i32,
-) -> Result<Vec<serde_json::Value>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Vec<serde_json::Value>, LabPulseError> {
+    let conn = db.0.get()?;
     
     // Check if single month or multi-month period
     let is_single_month = start_year == end_year && start_month == end_month;
@@ -1525,7 +2387,7 @@ pub fn get_office_rankings(
     // Get all offices first
     let mut stmt = conn.prepare(
         "SELECT office_id, office_name, address, model, dfo FROM offices ORDER BY office_id"
-    ).map_err(|e| e.to_string())?;
+    )?;
     
     let offices: Vec<(i64, String, String, String, String)> = stmt
         .query_map([], |row| {
@@ -1536,10 +2398,8 @@ pub fn get_office_rankings(
                 row.get(3)?,
                 row.get(4)?,
             ))
-        })
-        .map_err(|e| e.to_string())?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())?;
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
     
     let mut rankings = Vec::new();
     
@@ -1726,8 +2586,8 @@ pub fn get_office_rankings_by_month(
     month: i32,
     rank_by: String,
     time_period: String,
-) -> Result<Vec<serde_json::Value>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<Vec<serde_json::Value>, LabPulseError> {
+    let conn = db.0.get()?;
     
     // Calculate date range based on time_period
     let (start_year, start_month, end_year, end_month) = match time_period.as_str() {
@@ -1736,12 +2596,8 @@ pub fn get_office_rankings_by_month(
             (year, month, year, month)
         },
         "lastMonth" => {
-            // Go back one month
-            if month == 1 {
-                (year - 1, 12, year - 1, 12)
-            } else {
-                (year, month - 1, year, month - 1)
-            }
+            let (prev_year, prev_month) = previous_month(year, month);
+            (prev_year, prev_month, prev_year, prev_month)
         },
         "qtd" => {
             // From quarter start to end of selected month
@@ -1753,7 +2609,7 @@ pub fn get_office_rankings_by_month(
             (year, 1, year, month)
         },
         _ => {
-            return Err(format!("Invalid time_period: {}", time_period));
+            return Err(LabPulseError::Validation(format!("Invalid time_period: {}", time_period)));
         }
     };
     
@@ -1826,13 +2682,12 @@ pub fn get_office_rankings_by_month(
             )
         },
         _ => {
-            return Err(format!("Invalid rank_by metric: {}", rank_by));
+            return Err(LabPulseError::Validation(format!("Invalid rank_by metric: {}", rank_by)));
         }
     };
     
     // Execute query and get results
-    let mut stmt = conn.prepare(&format!("{} ORDER BY value {}", query, order_direction))
-        .map_err(|e| e.to_string())?;
+    let mut stmt = conn.prepare(&format!("{} ORDER BY value {}", query, order_direction))?;
     
     let results: Vec<(i64, String, f64)> = stmt
         .query_map(
@@ -1844,10 +2699,8 @@ pub fn get_office_rankings_by_month(
                     row.get::<_, f64>(2)?,
                 ))
             }
-        )
-        .map_err(|e| e.to_string())?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())?;
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
     
     // Build ranked results
     let mut rankings = Vec::new();
@@ -1866,14 +2719,14 @@ pub fn get_office_rankings_by_month(
 
 // Get all offices for directory
 #[tauri::command]
-pub fn get_directory_offices(db: State<DbConnection>) -> Result<Vec<serde_json::Value>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+pub fn get_directory_offices(db: State<DbConnection>) -> Result<Vec<serde_json::Value>, LabPulseError> {
+    let conn = db.0.get()?;
     
     let mut stmt = conn.prepare(
         "SELECT office_id, office_name, address, phone, managing_dentist, dfo, model, standardization_status 
          FROM offices 
          ORDER BY office_id"
-    ).map_err(|e| e.to_string())?;
+    )?;
     
     let offices = stmt.query_map([], |row| {
         Ok(serde_json::json!({
@@ -1886,18 +2739,16 @@ pub fn get_directory_offices(db: State<DbConnection>) -> Result<Vec<serde_json::
             "model": row.get::<_, String>(6)?,
             "standardization_status": row.get::<_, Option<String>>(7)?,
         }))
-    })
-    .map_err(|e| e.to_string())?
-    .collect::<Result<Vec<_>, _>>()
-    .map_err(|e| e.to_string())?;
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
     
     Ok(offices)
 }
 
 // Get all offices with lab manager data for export
 #[tauri::command]
-pub fn get_directory_offices_for_export(db: State<DbConnection>) -> Result<Vec<serde_json::Value>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+pub fn get_directory_offices_for_export(db: State<DbConnection>) -> Result<Vec<serde_json::Value>, LabPulseError> {
+    let conn = db.0.get()?;
     
     // Use a subquery to get only the first lab manager contact per office
     // This prevents duplicates when multiple contacts exist for the same office
@@ -1925,7 +2776,7 @@ pub fn get_directory_offices_for_export(db: State<DbConnection>) -> Result<Vec<s
              LIMIT 1) as lab_manager_phone
          FROM offices o
          ORDER BY o.office_id"
-    ).map_err(|e| e.to_string())?;
+    )?;
     
     let offices = stmt.query_map([], |row| {
         Ok(serde_json::json!({
@@ -1941,10 +2792,8 @@ pub fn get_directory_offices_for_export(db: State<DbConnection>) -> Result<Vec<s
             "lab_manager_email": row.get::<_, Option<String>>(9)?, // Will be NULL since column doesn't exist
             "lab_manager_phone": row.get::<_, Option<String>>(10)?,
         }))
-    })
-    .map_err(|e| e.to_string())?
-    .collect::<Result<Vec<_>, _>>()
-    .map_err(|e| e.to_string())?;
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
     
     Ok(offices)
 }
@@ -1954,8 +2803,8 @@ pub fn get_directory_offices_for_export(db: State<DbConnection>) -> Result<Vec<s
 pub fn get_directory_office_details(
     db: State<DbConnection>,
     office_id: i64,
-) -> Result<serde_json::Value, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<serde_json::Value, LabPulseError> {
+    let conn = db.0.get()?;
     
     // Get office information
     let office = conn.query_row(
@@ -1975,7 +2824,7 @@ pub fn get_directory_office_details(
                 "standardization_status": row.get::<_, Option<String>>(7)?,
             }))
         }
-    ).map_err(|e| e.to_string())?;
+    )?;
     
     // Get staff for this office
     let mut stmt = conn.prepare(
@@ -1983,7 +2832,7 @@ pub fn get_directory_office_details(
          FROM staff 
          WHERE office_id = ?1 
          ORDER BY hire_date"
-    ).map_err(|e| e.to_string())?;
+    )?;
     
     let staff: Vec<serde_json::Value> = stmt.query_map(params![office_id], |row| {
         Ok(serde_json::json!({
@@ -1991,10 +2840,8 @@ pub fn get_directory_office_details(
             "position": row.get::<_, String>(1)?, // Map job_title to position for frontend
             "hire_date": row.get::<_, Option<String>>(2)?,
         }))
-    })
-    .map_err(|e| e.to_string())?
-    .collect::<Result<Vec<_>, _>>()
-    .map_err(|e| e.to_string())?;
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
     
     // Get lab manager contact
     let lab_manager = conn.query_row(
@@ -2024,24 +2871,25 @@ pub fn get_directory_office_details(
 pub fn remove_office(
     db: State<DbConnection>,
     office_id: i64,
-) -> Result<String, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<String, LabPulseError> {
+    let conn = db.0.get()?;
     
     // Get office name for logging before deletion
     let office_name: String = conn.query_row(
         "SELECT office_name FROM offices WHERE office_id = ?1",
         params![office_id],
         |row| row.get(0)
-    ).map_err(|e| format!("Office not found: {}", e))?;
+    ).map_err(|e| match e {
+        rusqlite::Error::QueryReturnedNoRows => LabPulseError::NotFound(format!("Office {} not found", office_id)),
+        e => LabPulseError::from(e),
+    })?;
     
     // Temporarily disable foreign key constraints to allow deletion in any order
     // This is safe because we're deleting all related records anyway
-    conn.execute("PRAGMA foreign_keys = OFF", [])
-        .map_err(|e| format!("Failed to disable foreign keys: {}", e))?;
+    conn.execute("PRAGMA foreign_keys = OFF", [])?;
     
     // Start transaction
-    conn.execute("BEGIN TRANSACTION", [])
-        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+    conn.execute("BEGIN TRANSACTION", [])?;
     
     // Delete all child records first (explicit deletion for logging and safety)
     // Order: delete from all tables that reference office_id
@@ -2074,7 +2922,7 @@ pub fn remove_office(
                     // Rollback on other errors
                     let _ = conn.execute("ROLLBACK", []);
                     let _ = conn.execute("PRAGMA foreign_keys = ON", []);
-                    return Err(format!("Failed to delete from {}: {}", table_name, error_msg));
+                    return Err(e.into());
                 }
             }
         }
@@ -2089,16 +2937,14 @@ pub fn remove_office(
             if rows_deleted == 0 {
                 let _ = conn.execute("ROLLBACK", []);
                 let _ = conn.execute("PRAGMA foreign_keys = ON", []);
-                return Err("Office not found".to_string());
+                return Err(LabPulseError::NotFound("Office not found".to_string()));
             }
             
             // Commit transaction
-            conn.execute("COMMIT", [])
-                .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+            conn.execute("COMMIT", [])?;
             
             // Re-enable foreign keys
-            conn.execute("PRAGMA foreign_keys = ON", [])
-                .map_err(|e| format!("Failed to re-enable foreign keys: {}", e))?;
+            conn.execute("PRAGMA foreign_keys = ON", [])?;
             
             // Log deletion (console for now)
             println!("Office removed: {} (ID: {})", office_name, office_id);
@@ -2108,7 +2954,7 @@ pub fn remove_office(
         Err(e) => {
             let _ = conn.execute("ROLLBACK", []);
             let _ = conn.execute("PRAGMA foreign_keys = ON", []);
-            Err(format!("Failed to delete office: {}", e))
+            Err(e.into())
         }
     }
 }
@@ -2118,26 +2964,26 @@ pub fn remove_office(
 pub fn add_office_from_template(
     db: State<DbConnection>,
     office_data: serde_json::Value,
-) -> Result<String, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+) -> Result<String, LabPulseError> {
+    let conn = db.0.get()?;
     
     // Parse office data
     let office_id = office_data["office_id"]
         .as_i64()
-        .ok_or("Office ID is required and must be a number")?;
+        .ok_or_else(|| LabPulseError::Validation("Office ID is required and must be a number".to_string()))?;
     
     let office_name = office_data["office_name"]
         .as_str()
-        .ok_or("Office Name is required")?
+        .ok_or_else(|| LabPulseError::Validation("Office Name is required".to_string()))?
         .to_string();
     
     let model = office_data["model"]
         .as_str()
-        .ok_or("Model is required (must be PO or PLLC)")?
+        .ok_or_else(|| LabPulseError::Validation("Model is required (must be PO or PLLC)".to_string()))?
         .to_uppercase();
     
     if model != "PO" && model != "PLLC" {
-        return Err("Model must be PO or PLLC".to_string());
+        return Err(LabPulseError::Validation("Model must be PO or PLLC".to_string()));
     }
     
     let address = office_data["address"].as_str().map(|s| s.to_string());
@@ -2148,17 +2994,15 @@ pub fn add_office_from_template(
     // Combine address components
     let full_address = if let (Some(addr), Some(c), Some(s), Some(z)) = (address.clone(), city, state, zip) {
         Some(format!("{}, {}, {} {}", addr, c, s, z))
-    } else if let Some(addr) = address {
-        Some(addr)
     } else {
-        None
+        address
     };
     
     let phone = office_data["phone"].as_str().map(|s| s.to_string());
     let managing_dentist = office_data["managing_dentist"].as_str().map(|s| s.to_string());
     let dfo = office_data["dfo"]
         .as_str()
-        .ok_or("DFO is required")?
+        .ok_or_else(|| LabPulseError::Validation("DFO is required".to_string()))?
         .to_string();
     
     let standardization_status = office_data["standardization_status"]
@@ -2166,19 +3010,18 @@ pub fn add_office_from_template(
         .map(|s| s.to_string());
     
     // Start transaction
-    conn.execute("BEGIN TRANSACTION", [])
-        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+    conn.execute("BEGIN TRANSACTION", [])?;
     
     // Check if office already exists
     let exists: bool = conn.query_row(
         "SELECT EXISTS(SELECT 1 FROM offices WHERE office_id = ?1)",
         params![office_id],
         |row| row.get(0)
-    ).map_err(|e| format!("Failed to check office existence: {}", e))?;
+    )?;
     
     if exists {
         let _ = conn.execute("ROLLBACK", []);
-        return Err(format!("Office ID {} already exists", office_id));
+        return Err(LabPulseError::Validation(format!("Office ID {} already exists", office_id)));
     }
     
     // Insert office
@@ -2188,14 +3031,14 @@ pub fn add_office_from_template(
         params![office_id, office_name, model, full_address, phone, managing_dentist, dfo, standardization_status],
     ).map_err(|e| {
         let _ = conn.execute("ROLLBACK", []);
-        format!("Failed to insert office: {}", e)
+        LabPulseError::from(e)
     })?;
     
     // Insert lab manager contact if provided
     if let Some(lab_manager) = office_data.get("lab_manager") {
         let name = lab_manager["name"]
             .as_str()
-            .ok_or("Lab Manager name is required")?
+            .ok_or_else(|| LabPulseError::Validation("Lab Manager name is required".to_string()))?
             .to_string();
         
         let phone = lab_manager["phone"].as_str().map(|s| s.to_string());
@@ -2207,19 +3050,19 @@ pub fn add_office_from_template(
             params![office_id, role, name, phone],
         ).map_err(|e| {
             let _ = conn.execute("ROLLBACK", []);
-            format!("Failed to insert lab manager contact: {}", e)
+            LabPulseError::from(e)
         })?;
     }
     
     // Insert monthly financials if provided
     if let Some(financials) = office_data.get("monthly_financials").and_then(|f| f.as_array()) {
         for financial in financials {
-            let year = financial["year"].as_i64().ok_or("Year is required for financial data")? as i32;
-            let month = financial["month"].as_i64().ok_or("Month is required for financial data")? as i32;
+            let year = financial["year"].as_i64().ok_or_else(|| LabPulseError::Validation("Year is required for financial data".to_string()))? as i32;
+            let month = financial["month"].as_i64().ok_or_else(|| LabPulseError::Validation("Month is required for financial data".to_string()))? as i32;
             
-            if month < 1 || month > 12 {
+            if let Err(e) = validate_month(month) {
                 let _ = conn.execute("ROLLBACK", []);
-                return Err(format!("Invalid month: {}", month));
+                return Err(e);
             }
             
             let revenue = financial["revenue"].as_f64();
@@ -2238,7 +3081,7 @@ pub fn add_office_from_template(
                 params![office_id, year, month, revenue, lab_exp_no_outside, lab_exp_with_outside, outside_lab_spend, teeth_supplies, lab_supplies, personnel_exp, overtime_exp, bonus_exp],
             ).map_err(|e| {
                 let _ = conn.execute("ROLLBACK", []);
-                format!("Failed to insert financial data: {}", e)
+                LabPulseError::from(e)
             })?;
         }
     }
@@ -2246,12 +3089,12 @@ pub fn add_office_from_template(
     // Insert monthly operations if provided
     if let Some(operations) = office_data.get("monthly_ops").and_then(|o| o.as_array()) {
         for ops in operations {
-            let year = ops["year"].as_i64().ok_or("Year is required for operations data")? as i32;
-            let month = ops["month"].as_i64().ok_or("Month is required for operations data")? as i32;
+            let year = ops["year"].as_i64().ok_or_else(|| LabPulseError::Validation("Year is required for operations data".to_string()))? as i32;
+            let month = ops["month"].as_i64().ok_or_else(|| LabPulseError::Validation("Month is required for operations data".to_string()))? as i32;
             
-            if month < 1 || month > 12 {
+            if let Err(e) = validate_month(month) {
                 let _ = conn.execute("ROLLBACK", []);
-                return Err(format!("Invalid month: {}", month));
+                return Err(e);
             }
             
             let backlog_case_count = ops["backlog_case_count"].as_i64().map(|v| v as i32);
@@ -2264,35 +3107,32 @@ pub fn add_office_from_template(
                 params![office_id, year, month, backlog_case_count, overtime_value, labor_model_value],
             ).map_err(|e| {
                 let _ = conn.execute("ROLLBACK", []);
-                format!("Failed to insert operations data: {}", e)
+                LabPulseError::from(e)
             })?;
         }
     }
     
     // Commit transaction
-    conn.execute("COMMIT", [])
-        .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+    conn.execute("COMMIT", [])?;
     
     Ok(format!("Office '{}' (ID: {}) added successfully", office_name, office_id))
 }
 
 // Get submission compliance data with metrics
 #[tauri::command]
-pub fn get_compliance_data(db: State<DbConnection>) -> Result<Vec<serde_json::Value>, String> {
-    let conn = db.0.lock().map_err(|e| e.to_string())?;
+pub fn get_compliance_data(db: State<DbConnection>) -> Result<Vec<serde_json::Value>, LabPulseError> {
+    let conn = db.0.get()?;
     
     // Get all offices
     let mut stmt = conn.prepare(
         "SELECT office_id, office_name, dfo FROM offices ORDER BY office_id"
-    ).map_err(|e| e.to_string())?;
+    )?;
     
     let offices: Vec<(i64, String, String)> = stmt
         .query_map([], |row| {
             Ok((row.get(0)?, row.get(1)?, row.get(2)?))
-        })
-        .map_err(|e| e.to_string())?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())?;
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
     
     let mut compliance_data = Vec::new();
     
@@ -2303,15 +3143,13 @@ pub fn get_compliance_data(db: State<DbConnection>) -> Result<Vec<serde_json::Va
              FROM submission_compliance 
              WHERE office_id = ?1 
              ORDER BY year, week_number"
-        ).map_err(|e| e.to_string())?;
+        )?;
         
         let submissions: Vec<(i32, i32, i32)> = stmt
             .query_map(params![office_id], |row| {
                 Ok((row.get(0)?, row.get(1)?, row.get(2)?))
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
         
         if submissions.is_empty() {
             continue;
@@ -2383,3 +3221,5106 @@ pub fn get_compliance_data(db: State<DbConnection>) -> Result<Vec<serde_json::Va
     
     Ok(compliance_data)
 }
+
+// Unit tier names mapped to their monthly_volume / weekly_volume columns
+const UNIT_TIERS: [(&str, &str); 11] = [
+    ("immediate", "immediate_units"),
+    ("economy", "economy_units"),
+    ("economy_plus", "economy_plus_units"),
+    ("premium", "premium_units"),
+    ("ultimate", "ultimate_units"),
+    ("repair", "repair_units"),
+    ("reline", "reline_units"),
+    ("partial", "partial_units"),
+    ("retry", "retry_units"),
+    ("remake", "remake_units"),
+    ("bite_block", "bite_block_units"),
+];
+
+// Monthly unit counts for each UNIT_TIERS entry, in order, or None without a volume row
+fn load_tier_units(
+    conn: &Connection,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> rusqlite::Result<Option<Vec<i32>>> {
+    let columns = UNIT_TIERS.iter().map(|(_, col)| *col).collect::<Vec<_>>().join(", ");
+    match conn.query_row(
+        &format!(
+            "SELECT {} FROM monthly_volume WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+            columns
+        ),
+        params![office_id, year, month],
+        |row| (0..UNIT_TIERS.len()).map(|i| row.get::<_, i32>(i)).collect(),
+    ) {
+        Ok(counts) => Ok(Some(counts)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Tier revenue estimate structures
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TierRevenueLine {
+    pub tier: String,
+    pub units: i32,
+    pub price: f64,
+    pub estimated_revenue: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TierRevenue {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+    pub tiers: Vec<TierRevenueLine>,
+    pub total_estimate: f64,
+    pub actual_revenue: Option<f64>,
+}
+
+// Estimate revenue per unit tier from monthly volume and caller-supplied prices
+#[tauri::command]
+pub fn get_tier_revenue_estimate(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+    tier_prices: HashMap<String, f64>,
+) -> Result<TierRevenue, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    // Reject prices for tiers we don't track
+    for tier in tier_prices.keys() {
+        if !UNIT_TIERS.iter().any(|(name, _)| name == tier) {
+            return Err(LabPulseError::Validation(format!("Unknown unit tier: {}", tier)));
+        }
+    }
+    
+    let counts = load_tier_units(&conn, office_id, year, month)?.ok_or_else(|| {
+        LabPulseError::NotFound(format!("No volume data for office {} in {}/{}", office_id, month, year))
+    })?;
+    
+    let mut tiers = Vec::new();
+    let mut total_estimate = 0.0;
+    
+    for ((tier, _), units) in UNIT_TIERS.iter().zip(counts) {
+        // Only tiers the caller priced contribute to the estimate
+        if let Some(price) = tier_prices.get(*tier) {
+            let estimated_revenue = units as f64 * price;
+            total_estimate += estimated_revenue;
+            tiers.push(TierRevenueLine {
+                tier: tier.to_string(),
+                units,
+                price: *price,
+                estimated_revenue,
+            });
+        }
+    }
+    
+    // Actual revenue for comparison, if financials were entered
+    let actual_revenue: Option<f64> = conn.query_row(
+        "SELECT revenue FROM monthly_financials WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| row.get(0),
+    ).ok().flatten();
+    
+    Ok(TierRevenue {
+        office_id,
+        year,
+        month,
+        tiers,
+        total_estimate,
+        actual_revenue,
+    })
+}
+
+// Rows removed per table when clearing an office's month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClearSummary {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+    pub financials_removed: usize,
+    pub operations_removed: usize,
+    pub volume_removed: usize,
+    pub notes_removed: usize,
+}
+
+// Clear one office's monthly data so the period can be re-entered.
+// The office itself, its staff, and contacts are left untouched.
+#[tauri::command]
+pub fn clear_office_month(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<ClearSummary, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let tx = conn.unchecked_transaction()?;
+    
+    let delete_period = |table: &str| -> Result<usize, LabPulseError> {
+        tx.execute(
+            &format!("DELETE FROM {} WHERE office_id = ?1 AND year = ?2 AND month = ?3", table),
+            params![office_id, year, month],
+        ).map_err(LabPulseError::from)
+    };
+    
+    let financials_removed = delete_period("monthly_financials")?;
+    let operations_removed = delete_period("monthly_ops")?;
+    let volume_removed = delete_period("monthly_volume")?;
+    let notes_removed = delete_period("notes_actions")?;
+    
+    tx.commit()?;
+    
+    Ok(ClearSummary {
+        office_id,
+        year,
+        month,
+        financials_removed,
+        operations_removed,
+        volume_removed,
+        notes_removed,
+    })
+}
+
+// Pearson correlation coefficient; None with fewer than 3 pairs or zero variance
+fn pearson_correlation(pairs: &[(f64, f64)]) -> Option<f64> {
+    if pairs.len() < 3 {
+        return None;
+    }
+    
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    
+    let mut cov = 0.0;
+    let mut var_x = 0.0;
+    let mut var_y = 0.0;
+    for (x, y) in pairs {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x).powi(2);
+        var_y += (y - mean_y).powi(2);
+    }
+    
+    if var_x == 0.0 || var_y == 0.0 {
+        return None;
+    }
+    
+    Some(cov / (var_x.sqrt() * var_y.sqrt()))
+}
+
+// Correlate a month's overtime_exp with the following month's backlog_case_count
+#[tauri::command]
+pub fn get_overtime_backlog_correlation(
+    db: State<DbConnection>,
+    office_id: i64,
+) -> Result<Option<f64>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    // Pair each financial month with the ops row one month later (handles year rollover)
+    let mut stmt = conn.prepare(
+        "SELECT mf.overtime_exp, mo.backlog_case_count
+         FROM monthly_financials mf
+         JOIN monthly_ops mo ON mo.office_id = mf.office_id
+             AND (mo.year * 12 + mo.month) = (mf.year * 12 + mf.month) + 1
+         WHERE mf.office_id = ?1
+           AND mf.overtime_exp IS NOT NULL
+           AND mo.backlog_case_count IS NOT NULL
+         ORDER BY mf.year, mf.month"
+    )?;
+    
+    let pairs: Vec<(f64, f64)> = stmt
+        .query_map(params![office_id], |row| {
+            Ok((row.get::<_, f64>(0)?, row.get::<_, i64>(1)? as f64))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(pearson_correlation(&pairs))
+}
+
+// Correlate the staffing gap (required_staff - current_staff) with backlog_case_count
+// over an office's ops history; positive means understaffed months carry more backlog
+#[tauri::command]
+pub fn get_staffing_backlog_relationship(
+    db: State<DbConnection>,
+    office_id: i64,
+) -> Result<Option<f64>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT required_staff - current_staff, backlog_case_count
+         FROM monthly_ops
+         WHERE office_id = ?1
+           AND required_staff IS NOT NULL
+           AND current_staff IS NOT NULL
+           AND backlog_case_count IS NOT NULL
+         ORDER BY year, month"
+    )?;
+    
+    let pairs: Vec<(f64, f64)> = stmt
+        .query_map(params![office_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(pearson_correlation(&pairs))
+}
+
+// Stored volume totals recomputed from their component columns
+const VOLUME_BACKLOG_IN_LAB_SQL: &str =
+    "lab_setups + lab_fixed_cases + lab_over_denture + lab_processes + lab_finishes";
+const VOLUME_BACKLOG_IN_CLINIC_SQL: &str =
+    "clinic_wax_tryin + clinic_delivery + clinic_outside_lab + clinic_on_hold";
+const VOLUME_TOTAL_UNITS_SQL: &str =
+    "immediate_units + economy_units + economy_plus_units + premium_units + ultimate_units +
+     repair_units + reline_units + partial_units + retry_units + remake_units + bite_block_units";
+
+fn volume_mismatch_condition() -> String {
+    format!(
+        "backlog_in_lab != ({}) OR backlog_in_clinic != ({}) OR total_weekly_units != ({})",
+        VOLUME_BACKLOG_IN_LAB_SQL, VOLUME_BACKLOG_IN_CLINIC_SQL, VOLUME_TOTAL_UNITS_SQL
+    )
+}
+
+// monthly_volume row whose stored totals disagree with its components
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VolumeTotalMismatch {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+    pub stored_backlog_in_lab: i32,
+    pub computed_backlog_in_lab: i32,
+    pub stored_backlog_in_clinic: i32,
+    pub computed_backlog_in_clinic: i32,
+    pub stored_total_weekly_units: i32,
+    pub computed_total_weekly_units: i32,
+}
+
+// Find monthly_volume rows with inconsistent stored totals
+#[tauri::command]
+pub fn find_volume_total_mismatches(db: State<DbConnection>) -> Result<Vec<VolumeTotalMismatch>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(&format!(
+        "SELECT office_id, year, month,
+                backlog_in_lab, ({}),
+                backlog_in_clinic, ({}),
+                total_weekly_units, ({})
+         FROM monthly_volume
+         WHERE {}
+         ORDER BY office_id, year, month",
+        VOLUME_BACKLOG_IN_LAB_SQL,
+        VOLUME_BACKLOG_IN_CLINIC_SQL,
+        VOLUME_TOTAL_UNITS_SQL,
+        volume_mismatch_condition()
+    ))?;
+    
+    let mismatches = stmt.query_map([], |row| {
+        Ok(VolumeTotalMismatch {
+            office_id: row.get(0)?,
+            year: row.get(1)?,
+            month: row.get(2)?,
+            stored_backlog_in_lab: row.get(3)?,
+            computed_backlog_in_lab: row.get(4)?,
+            stored_backlog_in_clinic: row.get(5)?,
+            computed_backlog_in_clinic: row.get(6)?,
+            stored_total_weekly_units: row.get(7)?,
+            computed_total_weekly_units: row.get(8)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(mismatches)
+}
+
+// Recompute stored volume totals from components; returns rows actually corrected
+#[tauri::command]
+pub fn repair_volume_totals(db: State<DbConnection>) -> Result<usize, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let tx = conn.unchecked_transaction()?;
+    
+    // Only touch mismatched rows so the count reflects real corrections
+    let corrected = tx.execute(
+        &format!(
+            "UPDATE monthly_volume
+             SET backlog_in_lab = ({}),
+                 backlog_in_clinic = ({}),
+                 total_weekly_units = ({}),
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE {}",
+            VOLUME_BACKLOG_IN_LAB_SQL,
+            VOLUME_BACKLOG_IN_CLINIC_SQL,
+            VOLUME_TOTAL_UNITS_SQL,
+            volume_mismatch_condition()
+        ),
+        [],
+    )?;
+    
+    tx.commit()?;
+    
+    Ok(corrected)
+}
+
+// DFO filter options
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DfoList {
+    pub dfos: Vec<String>,
+    pub has_unassigned: bool,
+}
+
+// Get distinct DFOs for the filter dropdown, plus whether any office lacks one
+#[tauri::command]
+pub fn get_dfo_list(db: State<DbConnection>) -> Result<DfoList, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT dfo FROM offices
+         WHERE dfo IS NOT NULL AND TRIM(dfo) != ''
+         ORDER BY dfo"
+    )?;
+    
+    let dfos = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    // Empty strings count as unassigned too, since imports may leave them blank
+    let has_unassigned: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM offices WHERE dfo IS NULL OR TRIM(dfo) = '')",
+        [],
+        |row| row.get(0),
+    )?;
+    
+    Ok(DfoList { dfos, has_unassigned })
+}
+
+// One DFO's portfolio totals for a month; dfo is None for unassigned offices
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DfoSummary {
+    pub dfo: Option<String>,
+    pub office_count: i64,
+    pub offices_reporting: i64,
+    pub revenue: f64,
+    pub lab_exp_with_outside: f64,
+    pub total_expenses: f64,
+    pub margin_dollars: f64,
+    pub lab_exp_percent: Option<f64>,
+    pub margin_percent: Option<f64>,
+}
+
+// Roll a month's financials up by DFO, one row per DFO plus an unassigned bucket last
+#[tauri::command]
+pub fn get_dfo_rollup(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<Vec<DfoSummary>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    // Blank DFOs land in the unassigned bucket, matching get_dfo_list
+    let mut stmt = conn.prepare(&format!(
+        "SELECT NULLIF(TRIM(o.dfo), '') AS dfo_name,
+                COUNT(*),
+                COUNT(mf.office_id),
+                COALESCE(SUM(mf.revenue), 0),
+                COALESCE(SUM(mf.lab_exp_with_outside), 0),
+                COALESCE(SUM({}), 0)
+         FROM offices o
+         LEFT JOIN monthly_financials mf ON mf.office_id = o.office_id
+             AND mf.year = ?1 AND mf.month = ?2
+         GROUP BY dfo_name
+         ORDER BY dfo_name IS NULL, dfo_name",
+        total_expense_sql("mf.")
+    ))?;
+    
+    let rollup = stmt.query_map(params![year, month], |row| {
+        let revenue: f64 = row.get(3)?;
+        let lab_exp_with_outside: f64 = row.get(4)?;
+        let total_expenses: f64 = row.get(5)?;
+        let margin_dollars = revenue - total_expenses;
+        Ok(DfoSummary {
+            dfo: row.get(0)?,
+            office_count: row.get(1)?,
+            offices_reporting: row.get(2)?,
+            revenue,
+            lab_exp_with_outside,
+            total_expenses,
+            margin_dollars,
+            lab_exp_percent: (revenue > 0.0).then(|| lab_exp_with_outside / revenue * 100.0),
+            margin_percent: (revenue > 0.0).then(|| margin_dollars / revenue * 100.0),
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(rollup)
+}
+
+// Aggregates for one ownership model (PO or PLLC) in a month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModelSummary {
+    pub model: String,
+    pub office_count: i64,
+    pub offices_reporting: i64,
+    pub revenue: f64,
+    pub total_expenses: f64,
+    pub avg_lab_exp_percent: Option<f64>,
+    pub margin_percent: Option<f64>,
+}
+
+// Compare PO and PLLC offices for a month. avg_lab_exp_percent is the plain mean
+// of each office's lab expense percent, so a large office doesn't dominate.
+#[tauri::command]
+pub fn get_model_comparison(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<Vec<ModelSummary>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let mut stmt = conn.prepare(&format!(
+        "SELECT COUNT(*),
+                COUNT(mf.office_id),
+                COALESCE(SUM(mf.revenue), 0),
+                COALESCE(SUM({}), 0),
+                AVG(CASE WHEN mf.revenue > 0 THEN mf.lab_exp_with_outside * 100.0 / mf.revenue END)
+         FROM offices o
+         LEFT JOIN monthly_financials mf ON mf.office_id = o.office_id
+             AND mf.year = ?1 AND mf.month = ?2
+         WHERE o.model = ?3",
+        total_expense_sql("mf.")
+    ))?;
+    
+    // Both models always appear, even with no offices, so the comparison has two sides
+    let mut summaries = Vec::new();
+    for model in ["PO", "PLLC"] {
+        let summary = stmt.query_row(params![year, month, model], |row| {
+            let revenue: f64 = row.get(2)?;
+            let total_expenses: f64 = row.get(3)?;
+            Ok(ModelSummary {
+                model: model.to_string(),
+                office_count: row.get(0)?,
+                offices_reporting: row.get(1)?,
+                revenue,
+                total_expenses,
+                avg_lab_exp_percent: row.get(4)?,
+                margin_percent: (revenue > 0.0).then(|| (revenue - total_expenses) / revenue * 100.0),
+            })
+        })?;
+        summaries.push(summary);
+    }
+    
+    Ok(summaries)
+}
+
+// Quote a CSV field when it contains a delimiter, quote, or newline
+fn csv_escape(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') || value.contains('\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Export alerts raised in a period to CSV for compliance records
+#[tauri::command]
+pub fn export_alerts_csv(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    include_dismissed: bool,
+    output_path: String,
+) -> Result<String, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT a.office_id, o.office_name, a.alert_type, a.severity, a.message,
+                a.is_dismissed, a.created_at
+         FROM alerts a
+         JOIN offices o ON o.office_id = a.office_id
+         WHERE a.year = ?1 AND a.month = ?2
+           AND (?3 = 1 OR COALESCE(a.is_dismissed, 0) = 0)
+         ORDER BY a.office_id, a.created_at"
+    )?;
+    
+    let rows = stmt.query_map(params![year, month, include_dismissed], |row| {
+        Ok(vec![
+            row.get::<_, i64>(0)?.to_string(),
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+            row.get::<_, String>(4)?,
+            row.get::<_, Option<i64>>(5)?.unwrap_or(0).to_string(),
+            row.get::<_, Option<String>>(6)?.unwrap_or_default(),
+        ])
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut csv = String::from("office_id,office_name,alert_type,severity,message,is_dismissed,created_at\n");
+    for fields in rows {
+        let line = fields.iter().map(|f| csv_escape(f)).collect::<Vec<_>>().join(",");
+        csv.push_str(&line);
+        csv.push('\n');
+    }
+    
+    std::fs::write(&output_path, csv)
+        .map_err(|e| LabPulseError::Io(format!("Failed to write CSV file: {}", e)))?;
+    
+    Ok(output_path)
+}
+
+// Total monthly expense SQL: lab (incl. outside), supplies, and labor.
+// `prefix` is the table alias including the dot (e.g. "mf."), or "" for none.
+fn total_expense_sql(prefix: &str) -> String {
+    [
+        "lab_exp_with_outside", "teeth_supplies", "lab_supplies", "lab_hub",
+        "lss_expense", "personnel_exp", "overtime_exp", "bonus_exp",
+    ]
+    .iter()
+    .map(|col| format!("COALESCE({}{}, 0)", prefix, col))
+    .collect::<Vec<_>>()
+    .join(" + ")
+}
+
+// Competition ranking over already-sorted values: ties share a rank and the
+// next rank skips (1, 1, 3)
+fn competition_ranks(sorted_values: &[f64]) -> Vec<i32> {
+    let mut ranks = Vec::with_capacity(sorted_values.len());
+    let mut previous: Option<(f64, i32)> = None;
+    
+    for (idx, value) in sorted_values.iter().enumerate() {
+        let rank = match previous {
+            Some((prev_value, prev_rank)) if (prev_value - value).abs() < 1e-9 => prev_rank,
+            _ => (idx + 1) as i32,
+        };
+        ranks.push(rank);
+        previous = Some((*value, rank));
+    }
+    
+    ranks
+}
+
+// Margin leaderboard entry
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarginRank {
+    pub office_id: i64,
+    pub office_name: String,
+    pub rank: Option<i32>,
+    pub revenue: Option<f64>,
+    pub total_expenses: Option<f64>,
+    pub margin_percent: Option<f64>,
+}
+
+// Rank offices by margin percent; equal margins share a rank, offices without data go last
+#[tauri::command]
+pub fn rank_by_margin(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<Vec<MarginRank>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(&format!(
+        "SELECT o.office_id, o.office_name, mf.revenue, {}
+         FROM offices o
+         LEFT JOIN monthly_financials mf ON mf.office_id = o.office_id
+             AND mf.year = ?1 AND mf.month = ?2
+         ORDER BY o.office_id",
+        total_expense_sql("mf.")
+    ))?;
+    
+    let rows: Vec<(i64, String, Option<f64>, f64)> = stmt
+        .query_map(params![year, month], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut ranked = Vec::new();
+    let mut unranked = Vec::new();
+    
+    for (office_id, office_name, revenue, expenses) in rows {
+        match revenue {
+            Some(rev) if rev > 0.0 => ranked.push(MarginRank {
+                office_id,
+                office_name,
+                rank: None,
+                revenue: Some(rev),
+                total_expenses: Some(expenses),
+                margin_percent: Some((rev - expenses) / rev * 100.0),
+            }),
+            _ => unranked.push(MarginRank {
+                office_id,
+                office_name,
+                rank: None,
+                revenue,
+                total_expenses: revenue.map(|_| expenses),
+                margin_percent: None,
+            }),
+        }
+    }
+    
+    ranked.sort_by(|a, b| {
+        b.margin_percent.partial_cmp(&a.margin_percent).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    
+    let margins: Vec<f64> = ranked.iter().map(|r| r.margin_percent.unwrap_or(0.0)).collect();
+    for (entry, rank) in ranked.iter_mut().zip(competition_ranks(&margins)) {
+        entry.rank = Some(rank);
+    }
+    
+    ranked.extend(unranked);
+    Ok(ranked)
+}
+
+// Retries plus remakes as a percent of total weekly units; None when there is no volume
+fn rework_percent(retry_units: i64, remake_units: i64, total_weekly_units: i64) -> Option<f64> {
+    if total_weekly_units == 0 {
+        return None;
+    }
+    Some((retry_units + remake_units) as f64 / total_weekly_units as f64 * 100.0)
+}
+
+// Share of an office's month volume that was rework (retries + remakes), a quality proxy
+#[tauri::command]
+pub fn get_rework_rate(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<Option<f64>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let result = conn.query_row(
+        "SELECT retry_units, remake_units, total_weekly_units
+         FROM monthly_volume
+         WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    );
+    
+    match result {
+        Ok((retry, remake, total)) => Ok(rework_percent(retry, remake, total)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// Rework leaderboard entry
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReworkRank {
+    pub office_id: i64,
+    pub office_name: String,
+    pub rank: Option<i32>,
+    pub retry_units: i64,
+    pub remake_units: i64,
+    pub total_weekly_units: i64,
+    pub rework_percent: Option<f64>,
+}
+
+// Rank offices by rework rate, lowest first so quality outliers sit at the bottom;
+// offices without volume that month go last unranked
+#[tauri::command]
+pub fn rank_by_rework_rate(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<Vec<ReworkRank>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT o.office_id, o.office_name,
+                COALESCE(mv.retry_units, 0), COALESCE(mv.remake_units, 0),
+                COALESCE(mv.total_weekly_units, 0)
+         FROM offices o
+         LEFT JOIN monthly_volume mv ON mv.office_id = o.office_id
+             AND mv.year = ?1 AND mv.month = ?2
+         ORDER BY o.office_id"
+    )?;
+    
+    let entries: Vec<ReworkRank> = stmt
+        .query_map(params![year, month], |row| {
+            let retry_units: i64 = row.get(2)?;
+            let remake_units: i64 = row.get(3)?;
+            let total_weekly_units: i64 = row.get(4)?;
+            Ok(ReworkRank {
+                office_id: row.get(0)?,
+                office_name: row.get(1)?,
+                rank: None,
+                retry_units,
+                remake_units,
+                total_weekly_units,
+                rework_percent: rework_percent(retry_units, remake_units, total_weekly_units),
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let (mut ranked, unranked): (Vec<ReworkRank>, Vec<ReworkRank>) =
+        entries.into_iter().partition(|e| e.rework_percent.is_some());
+    
+    ranked.sort_by(|a, b| {
+        a.rework_percent.partial_cmp(&b.rework_percent).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    
+    let rates: Vec<f64> = ranked.iter().map(|r| r.rework_percent.unwrap_or(0.0)).collect();
+    for (entry, rank) in ranked.iter_mut().zip(competition_ranks(&rates)) {
+        entry.rank = Some(rank);
+    }
+    
+    ranked.extend(unranked);
+    Ok(ranked)
+}
+
+// Spreadsheet cell helpers shared by the adjustment/bulk importers
+fn cell_as_i64(cell: &calamine::Data) -> Option<i64> {
+    match cell {
+        calamine::Data::Int(i) => Some(*i),
+        calamine::Data::Float(f) => Some(*f as i64),
+        calamine::Data::String(s) => s.trim().parse::<i64>().ok(),
+        _ => None,
+    }
+}
+
+fn cell_as_f64(cell: &calamine::Data) -> Option<f64> {
+    match cell {
+        calamine::Data::Int(i) => Some(*i as f64),
+        calamine::Data::Float(f) => Some(*f),
+        calamine::Data::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+fn cell_as_string(cell: &calamine::Data) -> String {
+    match cell {
+        calamine::Data::String(s) => s.trim().to_string(),
+        calamine::Data::Int(i) => i.to_string(),
+        calamine::Data::Float(f) => f.to_string(),
+        _ => String::new(),
+    }
+}
+
+// monthly_financials columns that may be adjusted by delta.
+// outside_lab_spend is excluded because it is derived from the two lab expense columns.
+const ADJUSTABLE_FINANCIAL_FIELDS: [&str; 10] = [
+    "revenue",
+    "lab_exp_no_outside",
+    "lab_exp_with_outside",
+    "teeth_supplies",
+    "lab_supplies",
+    "lab_hub",
+    "lss_expense",
+    "personnel_exp",
+    "overtime_exp",
+    "bonus_exp",
+];
+
+// Apply accounting adjustments (field += delta) to existing financial rows.
+// Expected columns: office_id, year, month, field, delta.
+#[tauri::command]
+pub fn apply_financial_adjustments(
+    db: State<DbConnection>,
+    file_path: String,
+) -> Result<ImportSummary, LabPulseError> {
+    use calamine::{open_workbook, Reader, Xlsx};
+    
+    let conn = db.0.get()?;
+    
+    let mut workbook: Xlsx<_> = open_workbook(&file_path)
+        .map_err(|e| LabPulseError::Import(format!("Failed to open Excel file: {}", e)))?;
+    
+    let sheet = workbook
+        .worksheet_range_at(0)
+        .ok_or_else(|| LabPulseError::Import("No worksheets found in file".to_string()))?
+        .map_err(|e| LabPulseError::Import(format!("Failed to read sheet: {}", e)))?;
+    
+    let mut rows_processed = 0;
+    let mut rows_updated = 0;
+    let mut warnings = Vec::new();
+    
+    let content_hash = file_content_hash(&file_path);
+    if let Some(warning) = duplicate_import_warning(&conn, content_hash.as_deref()) {
+        warnings.push(warning);
+    }
+    
+    let tx = conn.unchecked_transaction()?;
+    
+    // Skip header row
+    for (idx, row) in sheet.rows().enumerate().skip(1) {
+        rows_processed += 1;
+        
+        let office_id = match row.first().and_then(cell_as_i64) {
+            Some(id) => id,
+            None => {
+                warnings.push(format!("Row {}: Missing or invalid office_id", idx + 1));
+                continue;
+            }
+        };
+        
+        let year = match row.get(1).and_then(cell_as_i64) {
+            Some(y) => y as i32,
+            None => {
+                warnings.push(format!("Row {}: Missing or invalid year", idx + 1));
+                continue;
+            }
+        };
+        
+        let month = match row.get(2).and_then(cell_as_i64) {
+            Some(m) if (1..=12).contains(&m) => m as i32,
+            _ => {
+                warnings.push(format!("Row {}: Missing or invalid month", idx + 1));
+                continue;
+            }
+        };
+        
+        let field = row.get(3).map(cell_as_string).unwrap_or_default().to_lowercase();
+        // Only whitelisted columns are ever interpolated into SQL
+        if !ADJUSTABLE_FINANCIAL_FIELDS.contains(&field.as_str()) {
+            warnings.push(format!("Row {}: Field '{}' cannot be adjusted", idx + 1, field));
+            continue;
+        }
+        
+        let delta = match row.get(4).and_then(cell_as_f64) {
+            Some(d) => d,
+            None => {
+                warnings.push(format!("Row {}: Missing or invalid delta", idx + 1));
+                continue;
+            }
+        };
+        
+        let result = tx.execute(
+            &format!(
+                "UPDATE monthly_financials
+                 SET {field} = COALESCE({field}, 0) + ?1,
+                     updated_at = CURRENT_TIMESTAMP
+                 WHERE office_id = ?2 AND year = ?3 AND month = ?4",
+                field = field
+            ),
+            params![delta, office_id, year, month],
+        ).and_then(|affected| {
+            // Recompute outside_lab_spend separately: SET expressions see the pre-update row
+            tx.execute(
+                "UPDATE monthly_financials
+                 SET outside_lab_spend = COALESCE(lab_exp_with_outside, 0) - COALESCE(lab_exp_no_outside, 0)
+                 WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+                params![office_id, year, month],
+            )?;
+            Ok(affected)
+        });
+        
+        match result {
+            Ok(0) => warnings.push(format!(
+                "Row {}: No financial record for office {} in {}/{}",
+                idx + 1, office_id, month, year
+            )),
+            Ok(_) => rows_updated += 1,
+            Err(e) => warnings.push(format!("Row {}: Failed to apply adjustment - {}", idx + 1, e)),
+        }
+    }
+    
+    tx.execute(
+        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings, content_hash)
+         VALUES ('financial_adjustments', ?1, ?2, 0, ?3, ?4, ?5)",
+        params![
+            file_path,
+            rows_processed,
+            rows_updated,
+            serde_json::to_string(&warnings).unwrap_or_default(),
+            content_hash
+        ],
+    )?;
+    
+    tx.commit()?;
+    
+    Ok(ImportSummary {
+        filename: file_path.split('\\').next_back().or_else(|| file_path.split('/').next_back()).unwrap_or(&file_path).to_string(),
+        rows_processed,
+        rows_inserted: 0,
+        rows_updated,
+        warnings,
+    })
+}
+
+// Backlog split between lab and clinic
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LabClinicSplit {
+    pub backlog_in_lab: i64,
+    pub backlog_in_clinic: i64,
+    pub lab_percent: f64,
+    pub clinic_percent: f64,
+}
+
+fn lab_clinic_split(backlog_in_lab: i64, backlog_in_clinic: i64) -> Option<LabClinicSplit> {
+    let total = backlog_in_lab + backlog_in_clinic;
+    if total == 0 {
+        return None;
+    }
+    
+    Some(LabClinicSplit {
+        backlog_in_lab,
+        backlog_in_clinic,
+        lab_percent: backlog_in_lab as f64 / total as f64 * 100.0,
+        clinic_percent: backlog_in_clinic as f64 / total as f64 * 100.0,
+    })
+}
+
+// Get the lab vs clinic backlog split for an office/month
+#[tauri::command]
+pub fn get_lab_vs_clinic_split(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<Option<LabClinicSplit>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let result = conn.query_row(
+        "SELECT backlog_in_lab, backlog_in_clinic FROM monthly_volume
+         WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+    );
+    
+    match result {
+        Ok((lab, clinic)) => Ok(lab_clinic_split(lab, clinic)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// Get the company-wide lab vs clinic backlog split for a month
+#[tauri::command]
+pub fn get_company_lab_vs_clinic_split(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<Option<LabClinicSplit>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let (lab, clinic) = conn.query_row(
+        "SELECT COALESCE(SUM(backlog_in_lab), 0), COALESCE(SUM(backlog_in_clinic), 0)
+         FROM monthly_volume
+         WHERE year = ?1 AND month = ?2",
+        params![year, month],
+        |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+    )?;
+    
+    Ok(lab_clinic_split(lab, clinic))
+}
+
+// Metrics that can be computed per office/month, named after OfficeSummary fields
+const OFFICE_METRICS: [&str; 7] = [
+    "revenue",
+    "lab_exp_percent",
+    "personnel_percent",
+    "overtime_percent",
+    "margin_percent",
+    "backlog_count",
+    "total_weekly_units",
+];
+
+fn validate_metric(metric: &str) -> Result<(), LabPulseError> {
+    if OFFICE_METRICS.contains(&metric) {
+        Ok(())
+    } else {
+        Err(LabPulseError::Validation(format!("Unknown metric: {} (expected one of {})", metric, OFFICE_METRICS.join(", "))))
+    }
+}
+
+// Raw monthly inputs for one office, used to derive any OFFICE_METRICS value
+struct OfficeMonthMetrics {
+    office_id: i64,
+    office_name: String,
+    revenue: Option<f64>,
+    lab_exp: Option<f64>,
+    personnel_exp: Option<f64>,
+    overtime_exp: Option<f64>,
+    total_expenses: Option<f64>,
+    backlog_count: Option<i32>,
+    total_weekly_units: Option<i32>,
+}
+
+impl OfficeMonthMetrics {
+    fn percent_of_revenue(&self, value: Option<f64>) -> Option<f64> {
+        match (self.revenue, value) {
+            (Some(rev), Some(v)) if rev > 0.0 => Some(v / rev * 100.0),
+            _ => None,
+        }
+    }
+    
+    fn value(&self, metric: &str) -> Option<f64> {
+        match metric {
+            "revenue" => self.revenue,
+            "lab_exp_percent" => self.percent_of_revenue(self.lab_exp),
+            "personnel_percent" => self.percent_of_revenue(self.personnel_exp),
+            "overtime_percent" => self.percent_of_revenue(self.overtime_exp),
+            "margin_percent" => self
+                .percent_of_revenue(self.total_expenses)
+                .map(|expense_pct| 100.0 - expense_pct),
+            "backlog_count" => self.backlog_count.map(|v| v as f64),
+            "total_weekly_units" => self.total_weekly_units.map(|v| v as f64),
+            _ => None,
+        }
+    }
+}
+
+// Load every office's monthly inputs for a period in a single query
+fn load_office_month_metrics(
+    conn: &Connection,
+    year: i32,
+    month: i32,
+) -> rusqlite::Result<Vec<OfficeMonthMetrics>> {
+    query_office_month_metrics(conn, year, month, None)
+}
+
+// Load one office's monthly inputs for a period
+fn load_single_office_month_metrics(
+    conn: &Connection,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> rusqlite::Result<Option<OfficeMonthMetrics>> {
+    Ok(query_office_month_metrics(conn, year, month, Some(office_id))?.into_iter().next())
+}
+
+fn query_office_month_metrics(
+    conn: &Connection,
+    year: i32,
+    month: i32,
+    office_id: Option<i64>,
+) -> rusqlite::Result<Vec<OfficeMonthMetrics>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT o.office_id, o.office_name,
+                mf.revenue, mf.lab_exp_with_outside, mf.personnel_exp, mf.overtime_exp,
+                CASE WHEN mf.id IS NULL THEN NULL ELSE {} END,
+                mo.backlog_case_count, mv.total_weekly_units
+         FROM offices o
+         LEFT JOIN monthly_financials mf ON mf.office_id = o.office_id AND mf.year = ?1 AND mf.month = ?2
+         LEFT JOIN monthly_ops mo ON mo.office_id = o.office_id AND mo.year = ?1 AND mo.month = ?2
+         LEFT JOIN monthly_volume mv ON mv.office_id = o.office_id AND mv.year = ?1 AND mv.month = ?2
+         WHERE ?3 IS NULL OR o.office_id = ?3
+         ORDER BY o.office_id",
+        total_expense_sql("mf.")
+    ))?;
+    
+    let rows = stmt.query_map(params![year, month, office_id], |row| {
+        Ok(OfficeMonthMetrics {
+            office_id: row.get(0)?,
+            office_name: row.get(1)?,
+            revenue: row.get(2)?,
+            lab_exp: row.get(3)?,
+            personnel_exp: row.get(4)?,
+            overtime_exp: row.get(5)?,
+            total_expenses: row.get(6)?,
+            backlog_count: row.get(7)?,
+            total_weekly_units: row.get(8)?,
+        })
+    })?;
+    
+    rows.collect()
+}
+
+// Office row for a configurable dashboard
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomSummary {
+    pub office_id: i64,
+    pub office_name: String,
+    pub metrics: HashMap<String, Option<f64>>,
+}
+
+// Get dashboard data limited to the metrics the caller asks for
+#[tauri::command]
+pub fn get_dashboard_custom(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    metrics: Vec<String>,
+) -> Result<Vec<CustomSummary>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    for metric in &metrics {
+        validate_metric(metric)?;
+    }
+    
+    let offices = load_office_month_metrics(&conn, year, month)?;
+    
+    Ok(offices
+        .into_iter()
+        .map(|office| CustomSummary {
+            metrics: metrics
+                .iter()
+                .map(|metric| (metric.clone(), office.value(metric)))
+                .collect(),
+            office_id: office.office_id,
+            office_name: office.office_name,
+        })
+        .collect())
+}
+
+// Supplies vs in-house lab expense sanity check
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SupplyRecon {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+    pub teeth_supplies: f64,
+    pub lab_supplies: f64,
+    pub lab_hub: f64,
+    pub lss_expense: f64,
+    pub supplies_total: f64,
+    pub lab_exp_no_outside: f64,
+    pub difference: f64,
+    pub supplies_exceed_lab_expense: bool,
+}
+
+// Compare summed supply components against the in-house lab expense.
+//
+// Heuristic: teeth, lab supplies, lab hub, and LSS are all spent inside the lab,
+// so together they should not exceed lab_exp_no_outside. When they do, one of
+// the figures was likely keyed into the wrong column or the lab total is stale.
+// This is a rough sanity relationship, not a strict accounting identity.
+#[tauri::command]
+pub fn check_supply_reconciliation(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<SupplyRecon, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let (teeth_supplies, lab_supplies, lab_hub, lss_expense, lab_exp_no_outside) = conn.query_row(
+        "SELECT COALESCE(teeth_supplies, 0), COALESCE(lab_supplies, 0), COALESCE(lab_hub, 0),
+                COALESCE(lss_expense, 0), COALESCE(lab_exp_no_outside, 0)
+         FROM monthly_financials
+         WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| {
+            Ok((
+                row.get::<_, f64>(0)?,
+                row.get::<_, f64>(1)?,
+                row.get::<_, f64>(2)?,
+                row.get::<_, f64>(3)?,
+                row.get::<_, f64>(4)?,
+            ))
+        },
+    ).map_err(|e| match e {
+        rusqlite::Error::QueryReturnedNoRows => LabPulseError::NotFound(format!(
+            "No financial data for office {} in {}/{}", office_id, month, year
+        )),
+        e => LabPulseError::from(e),
+    })?;
+    
+    let supplies_total = teeth_supplies + lab_supplies + lab_hub + lss_expense;
+    
+    Ok(SupplyRecon {
+        office_id,
+        year,
+        month,
+        teeth_supplies,
+        lab_supplies,
+        lab_hub,
+        lss_expense,
+        supplies_total,
+        lab_exp_no_outside,
+        difference: lab_exp_no_outside - supplies_total,
+        supplies_exceed_lab_expense: supplies_total > lab_exp_no_outside,
+    })
+}
+
+// Most recent note for an office
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LatestNote {
+    pub office_id: i64,
+    pub office_name: String,
+    pub year: i32,
+    pub month: i32,
+    pub note_text: String,
+    pub updated_at: Option<String>,
+}
+
+// Get each office's most recent note, newest first
+#[tauri::command]
+pub fn get_latest_notes(db: State<DbConnection>, limit: i64) -> Result<Vec<LatestNote>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT office_id, office_name, year, month, note_text, updated_at FROM (
+            SELECT n.office_id, o.office_name, n.year, n.month, n.note_text, n.updated_at,
+                   ROW_NUMBER() OVER (PARTITION BY n.office_id ORDER BY n.year DESC, n.month DESC) AS rn
+            FROM notes_actions n
+            JOIN offices o ON o.office_id = n.office_id
+            WHERE n.note_text IS NOT NULL AND TRIM(n.note_text) != ''
+         )
+         WHERE rn = 1
+         ORDER BY year DESC, month DESC, office_id
+         LIMIT ?1"
+    )?;
+    
+    let notes = stmt.query_map(params![limit], |row| {
+        Ok(LatestNote {
+            office_id: row.get(0)?,
+            office_name: row.get(1)?,
+            year: row.get(2)?,
+            month: row.get(3)?,
+            note_text: row.get(4)?,
+            updated_at: row.get(5)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(notes)
+}
+
+// Get the 12-month staffing_trend series for an office (index 0 = January)
+#[tauri::command]
+pub fn get_staffing_trend_series(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+) -> Result<Vec<Option<f64>>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT month, staffing_trend FROM monthly_ops
+         WHERE office_id = ?1 AND year = ?2"
+    )?;
+    
+    let rows = stmt
+        .query_map(params![office_id, year], |row| {
+            Ok((row.get::<_, i32>(0)?, row.get::<_, Option<f64>>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut series = vec![None; 12];
+    for (month, trend) in rows {
+        if (1..=12).contains(&month) {
+            series[(month - 1) as usize] = trend;
+        }
+    }
+    
+    Ok(series)
+}
+
+// Read a numeric value from the settings table, falling back to `default`
+pub(crate) fn setting_f64(conn: &Connection, key: &str, default: f64) -> f64 {
+    conn.query_row(
+        "SELECT value FROM settings WHERE key = ?1",
+        params![key],
+        |row| row.get::<_, String>(0),
+    )
+    .ok()
+    .and_then(|value| value.trim().parse::<f64>().ok())
+    .unwrap_or(default)
+}
+
+// Insert or replace a settings value
+fn upsert_setting(conn: &Connection, key: &str, value: &str) -> rusqlite::Result<usize> {
+    conn.execute(
+        "INSERT INTO settings (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        params![key, value],
+    )
+}
+
+// Overtime allowance as a percent of the modeled labor value (settings key below)
+const OVERTIME_ALLOWANCE_KEY: &str = "overtime_allowance_percent";
+const DEFAULT_OVERTIME_ALLOWANCE_PERCENT: f64 = 10.0;
+
+// Actual vs model-implied overtime
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OvertimeModelGap {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+    pub actual_overtime_percent: Option<f64>,
+    pub labor_model_value: Option<f64>,
+    pub overtime_allowance_percent: f64,
+    pub expected_overtime_percent: Option<f64>,
+    pub gap_points: Option<f64>,
+    pub exceeds_allowance: bool,
+}
+
+// Compare actual overtime percent of revenue to the overtime the labor model allows.
+// Expected overtime % = labor_model_value (labor % of revenue) * allowance %.
+#[tauri::command]
+pub fn get_overtime_vs_model(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<OvertimeModelGap, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let financials = conn.query_row(
+        "SELECT revenue, overtime_exp FROM monthly_financials
+         WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| Ok((row.get::<_, Option<f64>>(0)?, row.get::<_, Option<f64>>(1)?)),
+    );
+    
+    let actual_overtime_percent = match financials {
+        Ok((Some(rev), Some(ot))) if rev > 0.0 => Some(ot / rev * 100.0),
+        Ok(_) | Err(rusqlite::Error::QueryReturnedNoRows) => None,
+        Err(e) => return Err(e.into()),
+    };
+    
+    let labor_model_value: Option<f64> = match conn.query_row(
+        "SELECT labor_model_value FROM monthly_ops
+         WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| row.get(0),
+    ) {
+        Ok(value) => value,
+        Err(rusqlite::Error::QueryReturnedNoRows) => None,
+        Err(e) => return Err(e.into()),
+    };
+    
+    let overtime_allowance_percent = setting_f64(
+        &conn,
+        OVERTIME_ALLOWANCE_KEY,
+        DEFAULT_OVERTIME_ALLOWANCE_PERCENT,
+    );
+    
+    let expected_overtime_percent = labor_model_value.map(|labor| labor * overtime_allowance_percent / 100.0);
+    
+    let gap_points = match (actual_overtime_percent, expected_overtime_percent) {
+        (Some(actual), Some(expected)) => Some(actual - expected),
+        _ => None,
+    };
+    
+    Ok(OvertimeModelGap {
+        office_id,
+        year,
+        month,
+        actual_overtime_percent,
+        labor_model_value,
+        overtime_allowance_percent,
+        expected_overtime_percent,
+        gap_points,
+        exceeds_allowance: gap_points.is_some_and(|gap| gap > 0.0),
+    })
+}
+
+// Average months of service for staff with parseable hire dates
+fn office_average_tenure(conn: &Connection, office_id: i64) -> Result<Option<f64>, LabPulseError> {
+    let mut stmt = conn.prepare(
+        "SELECT hire_date FROM staff WHERE office_id = ?1 AND hire_date IS NOT NULL"
+    )?;
+    
+    let hire_dates = stmt
+        .query_map(params![office_id], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let today = chrono::Local::now().date_naive();
+    let tenures: Vec<f64> = hire_dates
+        .iter()
+        .filter_map(|raw| crate::imports::parse_hire_date(raw))
+        .filter(|hired| *hired <= today)
+        .map(|hired| (today - hired).num_days() as f64 / 30.4375)
+        .collect();
+    
+    if tenures.is_empty() {
+        return Ok(None);
+    }
+    
+    Ok(Some(tenures.iter().sum::<f64>() / tenures.len() as f64))
+}
+
+// Get the average staff tenure in months for an office
+#[tauri::command]
+pub fn get_average_tenure(db: State<DbConnection>, office_id: i64) -> Result<Option<f64>, LabPulseError> {
+    let conn = db.0.get()?;
+    office_average_tenure(&conn, office_id)
+}
+
+// Office tenure ranking entry
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OfficeTenure {
+    pub office_id: i64,
+    pub office_name: String,
+    pub rank: i32,
+    pub average_tenure_months: f64,
+}
+
+// Rank offices by average staff tenure (longest first); offices without dated staff are omitted
+#[tauri::command]
+pub fn get_tenure_rankings(db: State<DbConnection>) -> Result<Vec<OfficeTenure>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT office_id, office_name FROM offices ORDER BY office_id"
+    )?;
+    
+    let offices: Vec<(i64, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut tenures = Vec::new();
+    for (office_id, office_name) in offices {
+        if let Some(average) = office_average_tenure(&conn, office_id)? {
+            tenures.push((office_id, office_name, average));
+        }
+    }
+    
+    tenures.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+    
+    Ok(tenures
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (office_id, office_name, average_tenure_months))| OfficeTenure {
+            office_id,
+            office_name,
+            rank: (idx + 1) as i32,
+            average_tenure_months,
+        })
+        .collect())
+}
+
+// Escape text for inclusion in HTML
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+// One month of the report's financial table
+struct ReportFinancialRow {
+    month: i32,
+    revenue: Option<f64>,
+    lab_exp: Option<f64>,
+    personnel_exp: Option<f64>,
+    overtime_exp: Option<f64>,
+}
+
+// Export an office's year as a printable HTML report
+#[tauri::command]
+pub fn export_office_report_html(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    output_path: String,
+) -> Result<String, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let (office_name, model, dfo): (String, String, Option<String>) = conn.query_row(
+        "SELECT office_name, model, dfo FROM offices WHERE office_id = ?1",
+        params![office_id],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    ).map_err(|e| match e {
+        rusqlite::Error::QueryReturnedNoRows => LabPulseError::NotFound(format!("Office {} not found", office_id)),
+        e => LabPulseError::from(e),
+    })?;
+    
+    let money = |v: Option<f64>| v.map(|v| format!("${:.2}", v)).unwrap_or_else(|| "—".to_string());
+    let percent = |v: Option<f64>, rev: Option<f64>| match (v, rev) {
+        (Some(v), Some(rev)) if rev > 0.0 => format!("{:.1}%", v / rev * 100.0),
+        _ => "—".to_string(),
+    };
+    
+    // Financial table
+    let mut stmt = conn.prepare(
+        "SELECT month, revenue, lab_exp_with_outside, personnel_exp, overtime_exp
+         FROM monthly_financials
+         WHERE office_id = ?1 AND year = ?2
+         ORDER BY month"
+    )?;
+    
+    let financial_rows = stmt
+        .query_map(params![office_id, year], |row| {
+            Ok(ReportFinancialRow {
+                month: row.get(0)?,
+                revenue: row.get(1)?,
+                lab_exp: row.get(2)?,
+                personnel_exp: row.get(3)?,
+                overtime_exp: row.get(4)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut financial_html = String::new();
+    for row in &financial_rows {
+        financial_html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            MONTH_NAMES[(row.month - 1) as usize],
+            money(row.revenue),
+            money(row.lab_exp),
+            percent(row.lab_exp, row.revenue),
+            percent(row.personnel_exp, row.revenue),
+            percent(row.overtime_exp, row.revenue),
+        ));
+    }
+    
+    // Volume summary
+    let mut stmt = conn.prepare(
+        "SELECT month, total_weekly_units, backlog_in_lab, backlog_in_clinic
+         FROM monthly_volume
+         WHERE office_id = ?1 AND year = ?2
+         ORDER BY month"
+    )?;
+    
+    let volume_rows: Vec<(i32, i32, i32, i32)> = stmt
+        .query_map(params![office_id, year], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut volume_html = String::new();
+    for (month, units, lab_backlog, clinic_backlog) in &volume_rows {
+        volume_html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            MONTH_NAMES[(*month - 1) as usize], units, lab_backlog, clinic_backlog,
+        ));
+    }
+    
+    // Notes
+    let mut stmt = conn.prepare(
+        "SELECT month, note_text FROM notes_actions
+         WHERE office_id = ?1 AND year = ?2 AND note_text IS NOT NULL AND TRIM(note_text) != ''
+         ORDER BY month"
+    )?;
+    
+    let note_rows: Vec<(i32, String)> = stmt
+        .query_map(params![office_id, year], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut notes_html = String::new();
+    for (month, note) in &note_rows {
+        notes_html.push_str(&format!(
+            "<h3>{}</h3>\n<p>{}</p>\n",
+            MONTH_NAMES[(*month - 1) as usize],
+            html_escape(note).replace('\n', "<br>"),
+        ));
+    }
+    
+    let empty_row = |cols: usize| format!("<tr><td colspan=\"{}\">No data</td></tr>\n", cols);
+    
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{name} — {year} Report</title>
+<style>
+  body {{ font-family: Arial, sans-serif; margin: 32px; color: #222; }}
+  table {{ border-collapse: collapse; width: 100%; margin-bottom: 24px; }}
+  th, td {{ border: 1px solid #ccc; padding: 6px 10px; text-align: right; }}
+  th:first-child, td:first-child {{ text-align: left; }}
+  th {{ background: #f2f2f2; }}
+  @media print {{ body {{ margin: 0; }} }}
+</style>
+</head>
+<body>
+<h1>{name}</h1>
+<p>Office ID {office_id} · {model} · DFO: {dfo} · {year}</p>
+<h2>Financials</h2>
+<table>
+<tr><th>Month</th><th>Revenue</th><th>Lab Expense</th><th>Lab %</th><th>Personnel %</th><th>Overtime %</th></tr>
+{financials}</table>
+<h2>Volume</h2>
+<table>
+<tr><th>Month</th><th>Total Units</th><th>Backlog in Lab</th><th>Backlog in Clinic</th></tr>
+{volume}</table>
+<h2>Notes</h2>
+{notes}
+</body>
+</html>
+"#,
+        name = html_escape(&office_name),
+        office_id = office_id,
+        model = html_escape(&model),
+        dfo = html_escape(dfo.as_deref().unwrap_or("Unassigned")),
+        year = year,
+        financials = if financial_html.is_empty() { empty_row(6) } else { financial_html },
+        volume = if volume_html.is_empty() { empty_row(4) } else { volume_html },
+        notes = if notes_html.is_empty() { "<p>No notes.</p>\n".to_string() } else { notes_html },
+    );
+    
+    std::fs::write(&output_path, html)
+        .map_err(|e| LabPulseError::Io(format!("Failed to write report: {}", e)))?;
+    
+    Ok(output_path)
+}
+
+// Calendar month of an ISO week: the month containing the week's Thursday.
+// Week 53 in a year with only 52 ISO weeks is treated as the last week.
+fn month_for_week(year: i32, week: i32) -> i32 {
+    use chrono::{Datelike, NaiveDate, Weekday};
+    
+    let week = week.clamp(1, 53) as u32;
+    NaiveDate::from_isoywd_opt(year, week, Weekday::Thu)
+        .or_else(|| NaiveDate::from_isoywd_opt(year, 52, Weekday::Thu))
+        .map(|thursday| thursday.month() as i32)
+        .unwrap_or(12)
+}
+
+// First and last week numbers that month_for_week assigns to a month
+fn month_week_range(year: i32, month: i32) -> Option<(i32, i32)> {
+    let mut weeks = (1..=53).filter(|week| month_for_week(year, *week) == month);
+    let first = weeks.next()?;
+    Some((first, weeks.next_back().unwrap_or(first)))
+}
+
+// Unit count for a single tier
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TierUnits {
+    pub tier: String,
+    pub units: i64,
+}
+
+// Quarter volume rollup
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QuarterlyVolume {
+    pub office_id: i64,
+    pub year: i32,
+    pub quarter: i32,
+    pub tiers: Vec<TierUnits>,
+    pub total_units: i64,
+    pub months_included: i32,
+    pub weeks_included: i32,
+}
+
+// Sum monthly volume unit tiers over a quarter
+#[tauri::command]
+pub fn get_quarterly_volume(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    quarter: i32,
+) -> Result<QuarterlyVolume, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_quarter(quarter)?;
+    
+    let first_month = (quarter - 1) * 3 + 1;
+    let last_month = first_month + 2;
+    
+    let sums = UNIT_TIERS
+        .iter()
+        .map(|(_, col)| format!("COALESCE(SUM({}), 0)", col))
+        .collect::<Vec<_>>()
+        .join(", ");
+    
+    let (counts, months_included): (Vec<i64>, i32) = conn.query_row(
+        &format!(
+            "SELECT {}, COUNT(*) FROM monthly_volume
+             WHERE office_id = ?1 AND year = ?2 AND month BETWEEN ?3 AND ?4",
+            sums
+        ),
+        params![office_id, year, first_month, last_month],
+        |row| {
+            let counts = (0..UNIT_TIERS.len())
+                .map(|i| row.get::<_, i64>(i))
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            Ok((counts, row.get(UNIT_TIERS.len())?))
+        },
+    )?;
+    
+    let (week_start, _) = month_week_range(year, first_month).ok_or_else(|| LabPulseError::Validation("Invalid month".to_string()))?;
+    let (_, week_end) = month_week_range(year, last_month).ok_or_else(|| LabPulseError::Validation("Invalid month".to_string()))?;
+    
+    let weeks_included: i32 = conn.query_row(
+        "SELECT COUNT(*) FROM weekly_volume
+         WHERE office_id = ?1 AND year = ?2 AND week_number BETWEEN ?3 AND ?4",
+        params![office_id, year, week_start, week_end],
+        |row| row.get(0),
+    )?;
+    
+    let tiers: Vec<TierUnits> = UNIT_TIERS
+        .iter()
+        .zip(counts)
+        .map(|((tier, _), units)| TierUnits { tier: tier.to_string(), units })
+        .collect();
+    
+    Ok(QuarterlyVolume {
+        office_id,
+        year,
+        quarter,
+        total_units: tiers.iter().map(|t| t.units).sum(),
+        tiers,
+        months_included,
+        weeks_included,
+    })
+}
+
+// Highest-revenue month for an office
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PeakMonth {
+    pub office_id: i64,
+    pub office_name: String,
+    pub year: i32,
+    pub month: i32,
+    pub revenue: f64,
+}
+
+// Peak revenue month per office (ties resolved to the most recent month)
+fn query_peak_revenue_months(conn: &Connection, office_id: Option<i64>) -> Result<Vec<PeakMonth>, LabPulseError> {
+    let mut stmt = conn.prepare(
+        "SELECT office_id, office_name, year, month, revenue FROM (
+            SELECT mf.office_id, o.office_name, mf.year, mf.month, mf.revenue,
+                   ROW_NUMBER() OVER (
+                       PARTITION BY mf.office_id
+                       ORDER BY mf.revenue DESC, mf.year DESC, mf.month DESC
+                   ) AS rn
+            FROM monthly_financials mf
+            JOIN offices o ON o.office_id = mf.office_id
+            WHERE mf.revenue IS NOT NULL AND (?1 IS NULL OR mf.office_id = ?1)
+         )
+         WHERE rn = 1
+         ORDER BY office_id"
+    )?;
+    
+    let peaks = stmt.query_map(params![office_id], |row| {
+        Ok(PeakMonth {
+            office_id: row.get(0)?,
+            office_name: row.get(1)?,
+            year: row.get(2)?,
+            month: row.get(3)?,
+            revenue: row.get(4)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(peaks)
+}
+
+// Get an office's best revenue month ever
+#[tauri::command]
+pub fn get_peak_revenue_month(
+    db: State<DbConnection>,
+    office_id: i64,
+) -> Result<Option<PeakMonth>, LabPulseError> {
+    let conn = db.0.get()?;
+    Ok(query_peak_revenue_months(&conn, Some(office_id))?.into_iter().next())
+}
+
+// Get every office's best revenue month ever
+#[tauri::command]
+pub fn get_all_peak_revenue_months(db: State<DbConnection>) -> Result<Vec<PeakMonth>, LabPulseError> {
+    let conn = db.0.get()?;
+    query_peak_revenue_months(&conn, None)
+}
+
+// Revenue per unit for one month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthRatio {
+    pub year: i32,
+    pub month: i32,
+    pub revenue: f64,
+    pub total_units: i32,
+    pub revenue_per_unit: f64,
+}
+
+// Get revenue per unit by month; months missing revenue or units are skipped
+#[tauri::command]
+pub fn get_volume_revenue_ratio_trend(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+) -> Result<Vec<MonthRatio>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT mf.month, mf.revenue, mv.total_weekly_units
+         FROM monthly_financials mf
+         JOIN monthly_volume mv ON mv.office_id = mf.office_id
+             AND mv.year = mf.year AND mv.month = mf.month
+         WHERE mf.office_id = ?1 AND mf.year = ?2
+           AND mf.revenue IS NOT NULL AND mv.total_weekly_units > 0
+         ORDER BY mf.month"
+    )?;
+    
+    let ratios = stmt.query_map(params![office_id, year], |row| {
+        let revenue: f64 = row.get(1)?;
+        let total_units: i32 = row.get(2)?;
+        Ok(MonthRatio {
+            year,
+            month: row.get(0)?,
+            revenue,
+            total_units,
+            revenue_per_unit: revenue / total_units as f64,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(ratios)
+}
+
+// Office/period with financials or volume but not both
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataGap {
+    pub office_id: i64,
+    pub office_name: String,
+    pub year: i32,
+    pub month: i32,
+    pub has_financials: bool,
+    pub has_volume: bool,
+}
+
+// Find periods where financials exist without volume, or volume without financials
+#[tauri::command]
+pub fn find_data_gaps(db: State<DbConnection>) -> Result<Vec<DataGap>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT o.office_id, o.office_name, gaps.year, gaps.month, gaps.has_financials
+         FROM (
+            SELECT mf.office_id, mf.year, mf.month, 1 AS has_financials
+            FROM monthly_financials mf
+            WHERE NOT EXISTS (
+                SELECT 1 FROM monthly_volume mv
+                WHERE mv.office_id = mf.office_id AND mv.year = mf.year AND mv.month = mf.month
+            )
+            UNION ALL
+            SELECT mv.office_id, mv.year, mv.month, 0 AS has_financials
+            FROM monthly_volume mv
+            WHERE NOT EXISTS (
+                SELECT 1 FROM monthly_financials mf
+                WHERE mf.office_id = mv.office_id AND mf.year = mv.year AND mf.month = mv.month
+            )
+         ) gaps
+         JOIN offices o ON o.office_id = gaps.office_id
+         ORDER BY o.office_id, gaps.year, gaps.month"
+    )?;
+    
+    let gaps = stmt.query_map([], |row| {
+        let has_financials: bool = row.get(4)?;
+        Ok(DataGap {
+            office_id: row.get(0)?,
+            office_name: row.get(1)?,
+            year: row.get(2)?,
+            month: row.get(3)?,
+            has_financials,
+            has_volume: !has_financials,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(gaps)
+}
+
+// Calendar month before the given one, rolling back into December of the prior year
+fn previous_month(year: i32, month: i32) -> (i32, i32) {
+    if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    }
+}
+
+// Whether a larger value of the metric is the favorable direction
+fn metric_higher_is_better(metric: &str) -> bool {
+    !matches!(
+        metric,
+        "lab_exp_percent" | "personnel_percent" | "overtime_percent" | "backlog_count"
+    )
+}
+
+// Rank offices on a metric for one month; offices without a value are left out
+fn rank_offices_by_metric(
+    offices: &[OfficeMonthMetrics],
+    metric: &str,
+) -> HashMap<i64, (i32, f64)> {
+    let mut values: Vec<(i64, f64)> = offices
+        .iter()
+        .filter_map(|office| office.value(metric).map(|v| (office.office_id, v)))
+        .collect();
+    
+    let higher_is_better = metric_higher_is_better(metric);
+    values.sort_by(|a, b| {
+        let ordering = a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal);
+        if higher_is_better { ordering.reverse() } else { ordering }
+    });
+    
+    let sorted: Vec<f64> = values.iter().map(|(_, v)| *v).collect();
+    values
+        .iter()
+        .zip(competition_ranks(&sorted))
+        .map(|((office_id, value), rank)| (*office_id, (rank, *value)))
+        .collect()
+}
+
+// Office rank movement versus the prior month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RankChange {
+    pub office_id: i64,
+    pub office_name: String,
+    pub value: f64,
+    pub current_rank: i32,
+    pub previous_rank: Option<i32>,
+    // Positive = moved up that many positions, negative = moved down
+    pub change: Option<i32>,
+}
+
+// Rank offices this month and last month on a metric and report the movement
+#[tauri::command]
+pub fn get_rank_change(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    metric: String,
+) -> Result<Vec<RankChange>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_metric(&metric)?;
+    
+    let (prev_year, prev_month) = previous_month(year, month);
+    let current = load_office_month_metrics(&conn, year, month)?;
+    
+    let current_ranks = rank_offices_by_metric(&current, &metric);
+    // Prefer the frozen ranking for last month when one was snapshotted
+    let snapshot = load_ranking_snapshot(&conn, prev_year, prev_month, &metric)?;
+    let previous_ranks = if snapshot.is_empty() {
+        let previous = load_office_month_metrics(&conn, prev_year, prev_month)?;
+        rank_offices_by_metric(&previous, &metric)
+    } else {
+        snapshot
+    };
+    
+    let mut changes: Vec<RankChange> = current
+        .into_iter()
+        .filter_map(|office| {
+            let (current_rank, value) = current_ranks.get(&office.office_id).copied()?;
+            let previous_rank = previous_ranks.get(&office.office_id).map(|(rank, _)| *rank);
+            Some(RankChange {
+                office_id: office.office_id,
+                office_name: office.office_name,
+                value,
+                current_rank,
+                previous_rank,
+                change: previous_rank.map(|prev| prev - current_rank),
+            })
+        })
+        .collect();
+    
+    changes.sort_by_key(|c| (c.current_rank, c.office_id));
+    Ok(changes)
+}
+
+// Stored rank and value per office for a snapshotted month
+fn load_ranking_snapshot(
+    conn: &Connection,
+    year: i32,
+    month: i32,
+    metric: &str,
+) -> rusqlite::Result<HashMap<i64, (i32, f64)>> {
+    let mut stmt = conn.prepare(
+        "SELECT office_id, rank, value FROM ranking_snapshots
+         WHERE year = ?1 AND month = ?2 AND metric = ?3"
+    )?;
+    let ranks = stmt
+        .query_map(params![year, month, metric], |row| {
+            Ok((row.get(0)?, (row.get(1)?, row.get(2)?)))
+        })?
+        .collect();
+    ranks
+}
+
+// Compute a month's ranking on a metric and store it, replacing any earlier
+// snapshot for the same month and metric; returns how many offices were ranked
+#[tauri::command]
+pub fn snapshot_rankings(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    metric: String,
+) -> Result<usize, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_metric(&metric)?;
+    validate_month(month)?;
+    
+    let offices = load_office_month_metrics(&conn, year, month)?;
+    let ranks = rank_offices_by_metric(&offices, &metric);
+    
+    let tx = conn.unchecked_transaction()?;
+    tx.execute(
+        "DELETE FROM ranking_snapshots WHERE year = ?1 AND month = ?2 AND metric = ?3",
+        params![year, month, metric],
+    )?;
+    for (office_id, (rank, value)) in &ranks {
+        tx.execute(
+            "INSERT INTO ranking_snapshots (office_id, year, month, metric, rank, value)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![office_id, year, month, metric, rank, value],
+        )?;
+    }
+    tx.commit()?;
+    
+    Ok(ranks.len())
+}
+
+// One office's place in a stored ranking
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RankingSnapshotEntry {
+    pub office_id: i64,
+    pub office_name: String,
+    pub rank: i32,
+    pub value: f64,
+    pub snapshot_at: String,
+}
+
+// Read back a stored ranking, best rank first; empty if the month was never snapshotted
+#[tauri::command]
+pub fn get_ranking_snapshot(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    metric: String,
+) -> Result<Vec<RankingSnapshotEntry>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_metric(&metric)?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT rs.office_id, o.office_name, rs.rank, rs.value, rs.created_at
+         FROM ranking_snapshots rs
+         JOIN offices o ON o.office_id = rs.office_id
+         WHERE rs.year = ?1 AND rs.month = ?2 AND rs.metric = ?3
+         ORDER BY rs.rank, rs.office_id"
+    )?;
+    
+    let entries = stmt.query_map(params![year, month, metric], |row| {
+        Ok(RankingSnapshotEntry {
+            office_id: row.get(0)?,
+            office_name: row.get(1)?,
+            rank: row.get(2)?,
+            value: row.get(3)?,
+            snapshot_at: row.get(4)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(entries)
+}
+
+// Health score component weights (settings keys and defaults)
+const HEALTH_WEIGHTS: [(&str, &str, f64); 4] = [
+    ("lab_exp_percent", "health_weight_lab_exp", 0.3),
+    ("margin_percent", "health_weight_margin", 0.3),
+    ("backlog_weeks", "health_weight_backlog", 0.2),
+    ("overtime_percent", "health_weight_overtime", 0.2),
+];
+
+// Linear 0-100 sub-score: 100 at `best`, 0 at `worst`, clamped in between
+fn linear_score(value: f64, best: f64, worst: f64) -> f64 {
+    ((value - worst) / (best - worst) * 100.0).clamp(0.0, 100.0)
+}
+
+// One component of the health score
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthComponent {
+    pub component: String,
+    pub raw_value: Option<f64>,
+    pub sub_score: Option<f64>,
+    pub weight: f64,
+    pub contribution: Option<f64>,
+}
+
+// Composite 0-100 office health score
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthScore {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+    pub score: Option<f64>,
+    pub components: Vec<HealthComponent>,
+}
+
+// Compute a weighted 0-100 health score for an office/month.
+//
+// Sub-scores are linear between fixed bands:
+//   lab expense %   100 at <= 10%, 0 at >= 30%
+//   margin %        100 at >= 40%, 0 at <= 0%
+//   backlog weeks   100 at <= 1 week of units, 0 at >= 6 weeks
+//   overtime %      100 at 0%, 0 at >= 5%
+// Components without data are dropped and the remaining weights rescaled.
+#[tauri::command]
+pub fn get_office_health_score(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<HealthScore, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let result = conn.query_row(
+        &format!(
+            "SELECT mf.revenue, mf.lab_exp_with_outside, mf.overtime_exp,
+                    CASE WHEN mf.id IS NULL THEN NULL ELSE {} END,
+                    mo.backlog_case_count, mv.total_weekly_units
+             FROM offices o
+             LEFT JOIN monthly_financials mf ON mf.office_id = o.office_id AND mf.year = ?2 AND mf.month = ?3
+             LEFT JOIN monthly_ops mo ON mo.office_id = o.office_id AND mo.year = ?2 AND mo.month = ?3
+             LEFT JOIN monthly_volume mv ON mv.office_id = o.office_id AND mv.year = ?2 AND mv.month = ?3
+             WHERE o.office_id = ?1",
+            total_expense_sql("mf.")
+        ),
+        params![office_id, year, month],
+        |row| {
+            Ok((
+                row.get::<_, Option<f64>>(0)?,
+                row.get::<_, Option<f64>>(1)?,
+                row.get::<_, Option<f64>>(2)?,
+                row.get::<_, Option<f64>>(3)?,
+                row.get::<_, Option<i64>>(4)?,
+                row.get::<_, Option<i64>>(5)?,
+            ))
+        },
+    );
+    
+    let (revenue, lab_exp, overtime_exp, total_expenses, backlog, weekly_units) = match result {
+        Ok(values) => values,
+        Err(rusqlite::Error::QueryReturnedNoRows) => return Err(LabPulseError::NotFound(format!("Office {} not found", office_id))),
+        Err(e) => return Err(e.into()),
+    };
+    
+    let percent_of_revenue = |value: Option<f64>| match (revenue, value) {
+        (Some(rev), Some(v)) if rev > 0.0 => Some(v / rev * 100.0),
+        _ => None,
+    };
+    
+    let lab_exp_percent = percent_of_revenue(lab_exp);
+    let margin_percent = percent_of_revenue(total_expenses).map(|pct| 100.0 - pct);
+    let overtime_percent = percent_of_revenue(overtime_exp);
+    let backlog_weeks = match (backlog, weekly_units) {
+        (Some(cases), Some(units)) if units > 0 => Some(cases as f64 / units as f64),
+        _ => None,
+    };
+    
+    let mut components = Vec::new();
+    for (component, key, default_weight) in HEALTH_WEIGHTS {
+        let raw_value = match component {
+            "lab_exp_percent" => lab_exp_percent,
+            "margin_percent" => margin_percent,
+            "backlog_weeks" => backlog_weeks,
+            _ => overtime_percent,
+        };
+        let sub_score = raw_value.map(|v| match component {
+            "lab_exp_percent" => linear_score(v, 10.0, 30.0),
+            "margin_percent" => linear_score(v, 40.0, 0.0),
+            "backlog_weeks" => linear_score(v, 1.0, 6.0),
+            _ => linear_score(v, 0.0, 5.0),
+        });
+        components.push(HealthComponent {
+            component: component.to_string(),
+            raw_value,
+            sub_score,
+            weight: setting_f64(&conn, key, default_weight).max(0.0),
+            contribution: None,
+        });
+    }
+    
+    // Reweight across the components that have data
+    let available_weight: f64 = components
+        .iter()
+        .filter(|c| c.sub_score.is_some())
+        .map(|c| c.weight)
+        .sum();
+    
+    let score = if available_weight > 0.0 {
+        for c in components.iter_mut() {
+            c.contribution = c.sub_score.map(|s| s * c.weight / available_weight);
+        }
+        Some(components.iter().filter_map(|c| c.contribution).sum())
+    } else {
+        None
+    };
+    
+    Ok(HealthScore {
+        office_id,
+        year,
+        month,
+        score,
+        components,
+    })
+}
+
+// Office improvement on a metric versus the prior month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Improvement {
+    pub office_id: i64,
+    pub office_name: String,
+    pub current_value: f64,
+    pub previous_value: f64,
+    // Raw change (current - previous)
+    pub change: f64,
+    // Change in the favorable direction for this metric; always positive here
+    pub improvement: f64,
+}
+
+// List offices with the largest favorable change on a metric versus the prior month
+#[tauri::command]
+pub fn get_most_improved(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    metric: String,
+    limit: i64,
+) -> Result<Vec<Improvement>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_metric(&metric)?;
+    
+    let (prev_year, prev_month) = previous_month(year, month);
+    let current = load_office_month_metrics(&conn, year, month)?;
+    let previous: HashMap<i64, OfficeMonthMetrics> = load_office_month_metrics(&conn, prev_year, prev_month)?
+        .into_iter()
+        .map(|office| (office.office_id, office))
+        .collect();
+    
+    // Lower is better for expense ratios and backlog, so flip their sign
+    let direction = if metric_higher_is_better(&metric) { 1.0 } else { -1.0 };
+    
+    let mut improvements: Vec<Improvement> = current
+        .into_iter()
+        .filter_map(|office| {
+            let current_value = office.value(&metric)?;
+            let previous_value = previous.get(&office.office_id)?.value(&metric)?;
+            let change = current_value - previous_value;
+            let improvement = change * direction;
+            if improvement <= 0.0 {
+                return None;
+            }
+            Some(Improvement {
+                office_id: office.office_id,
+                office_name: office.office_name,
+                current_value,
+                previous_value,
+                change,
+                improvement,
+            })
+        })
+        .collect();
+    
+    improvements.sort_by(|a, b| {
+        b.improvement.partial_cmp(&a.improvement).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    improvements.truncate(limit.max(0) as usize);
+    
+    Ok(improvements)
+}
+
+// Latest (year, month) with financial, ops, or volume data for an office
+fn latest_data_period(conn: &Connection, office_id: i64) -> rusqlite::Result<Option<(i32, i32)>> {
+    let result = conn.query_row(
+        "SELECT year, month FROM (
+            SELECT year, month FROM monthly_financials WHERE office_id = ?1
+            UNION
+            SELECT year, month FROM monthly_ops WHERE office_id = ?1
+            UNION
+            SELECT year, month FROM monthly_volume WHERE office_id = ?1
+         ) ORDER BY year DESC, month DESC LIMIT 1",
+        params![office_id],
+        |row| Ok((row.get::<_, i32>(0)?, row.get::<_, i32>(1)?)),
+    );
+    
+    match result {
+        Ok(period) => Ok(Some(period)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Forecast next month's metric as the trailing `window`-month average ending at the
+// office's latest period with data. None unless every month in the window has a value.
+#[tauri::command]
+pub fn forecast_next_month(
+    db: State<DbConnection>,
+    office_id: i64,
+    metric: String,
+    window: i32,
+) -> Result<Option<f64>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_metric(&metric)?;
+    if window < 1 {
+        return Err(LabPulseError::Validation(format!("Invalid window {} (must be at least 1)", window)));
+    }
+    
+    let (mut year, mut month) = match latest_data_period(&conn, office_id)? {
+        Some(period) => period,
+        None => return Ok(None),
+    };
+    
+    let mut values = Vec::new();
+    for _ in 0..window {
+        let value = load_single_office_month_metrics(&conn, office_id, year, month)?
+            .and_then(|office| office.value(&metric));
+        match value {
+            Some(v) => values.push(v),
+            None => return Ok(None),
+        }
+        (year, month) = previous_month(year, month);
+    }
+    
+    Ok(Some(values.iter().sum::<f64>() / values.len() as f64))
+}
+
+// Ops vs financial overtime for one month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OvertimeReconPoint {
+    pub year: i32,
+    pub month: i32,
+    pub ops_overtime_value: Option<f64>,
+    pub financial_overtime_exp: Option<f64>,
+    // ops_overtime_value - financial_overtime_exp, when both exist
+    pub difference: Option<f64>,
+}
+
+// Compare monthly_ops overtime_value with monthly_financials overtime_exp across a year
+#[tauri::command]
+pub fn get_overtime_recon_series(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+) -> Result<Vec<OvertimeReconPoint>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT periods.month, mo.overtime_value, mf.overtime_exp
+         FROM (
+            SELECT month FROM monthly_ops WHERE office_id = ?1 AND year = ?2
+            UNION
+            SELECT month FROM monthly_financials WHERE office_id = ?1 AND year = ?2
+         ) periods
+         LEFT JOIN monthly_ops mo ON mo.office_id = ?1 AND mo.year = ?2 AND mo.month = periods.month
+         LEFT JOIN monthly_financials mf ON mf.office_id = ?1 AND mf.year = ?2 AND mf.month = periods.month
+         ORDER BY periods.month"
+    )?;
+    
+    let points = stmt.query_map(params![office_id, year], |row| {
+        let ops_overtime_value: Option<f64> = row.get(1)?;
+        let financial_overtime_exp: Option<f64> = row.get(2)?;
+        Ok(OvertimeReconPoint {
+            year,
+            month: row.get(0)?,
+            ops_overtime_value,
+            financial_overtime_exp,
+            difference: match (ops_overtime_value, financial_overtime_exp) {
+                (Some(ops), Some(fin)) => Some(ops - fin),
+                _ => None,
+            },
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(points)
+}
+
+// Units in one high-level volume group
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VolumeGroupTotal {
+    pub total: i64,
+    pub percent: Option<f64>,
+}
+
+// Lab vs clinic vs product-tier volume split
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VolumeGrouping {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+    pub lab: VolumeGroupTotal,
+    pub clinic: VolumeGroupTotal,
+    pub product_tiers: VolumeGroupTotal,
+    pub overall_total: i64,
+}
+
+// Sum lab_*, clinic_*, and product-tier columns separately with each group's share
+#[tauri::command]
+pub fn get_volume_grouping(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<VolumeGrouping, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let (lab, clinic, tiers): (i64, i64, i64) = conn.query_row(
+        &format!(
+            "SELECT ({}), ({}), ({}) FROM monthly_volume
+             WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+            VOLUME_BACKLOG_IN_LAB_SQL, VOLUME_BACKLOG_IN_CLINIC_SQL, VOLUME_TOTAL_UNITS_SQL
+        ),
+        params![office_id, year, month],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    ).map_err(|e| match e {
+        rusqlite::Error::QueryReturnedNoRows => LabPulseError::NotFound(format!(
+            "No volume data for office {} in {}/{}", office_id, month, year
+        )),
+        e => LabPulseError::from(e),
+    })?;
+    
+    let overall_total = lab + clinic + tiers;
+    let group = |total: i64| VolumeGroupTotal {
+        total,
+        percent: if overall_total > 0 {
+            Some(total as f64 / overall_total as f64 * 100.0)
+        } else {
+            None
+        },
+    };
+    
+    Ok(VolumeGrouping {
+        office_id,
+        year,
+        month,
+        lab: group(lab),
+        clinic: group(clinic),
+        product_tiers: group(tiers),
+        overall_total,
+    })
+}
+
+// Expense anomaly settings: minimum jump in expense % points, and the largest
+// revenue swing (%) for which that jump is still considered unexplained
+const EXPENSE_ANOMALY_THRESHOLD_KEY: &str = "expense_anomaly_threshold_points";
+const DEFAULT_EXPENSE_ANOMALY_THRESHOLD_POINTS: f64 = 5.0;
+const EXPENSE_ANOMALY_REVENUE_TOLERANCE_KEY: &str = "expense_anomaly_revenue_tolerance_percent";
+const DEFAULT_EXPENSE_ANOMALY_REVENUE_TOLERANCE_PERCENT: f64 = 5.0;
+
+// Office whose expense ratio jumped without a matching revenue move
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExpenseAnomaly {
+    pub office_id: i64,
+    pub office_name: String,
+    pub current_expense_percent: f64,
+    pub previous_expense_percent: f64,
+    pub expense_percent_delta: f64,
+    pub current_revenue: f64,
+    pub previous_revenue: f64,
+    pub revenue_change_percent: f64,
+}
+
+// Find offices whose total expense % rose past the threshold versus last month
+// while revenue stayed roughly flat, largest jump first
+#[tauri::command]
+pub fn find_expense_anomalies(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<Vec<ExpenseAnomaly>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let threshold = setting_f64(&conn, EXPENSE_ANOMALY_THRESHOLD_KEY, DEFAULT_EXPENSE_ANOMALY_THRESHOLD_POINTS);
+    let revenue_tolerance = setting_f64(
+        &conn,
+        EXPENSE_ANOMALY_REVENUE_TOLERANCE_KEY,
+        DEFAULT_EXPENSE_ANOMALY_REVENUE_TOLERANCE_PERCENT,
+    );
+    
+    let (prev_year, prev_month) = previous_month(year, month);
+    let current = load_office_month_metrics(&conn, year, month)?;
+    let previous: HashMap<i64, OfficeMonthMetrics> = load_office_month_metrics(&conn, prev_year, prev_month)?
+        .into_iter()
+        .map(|office| (office.office_id, office))
+        .collect();
+    
+    let mut anomalies: Vec<ExpenseAnomaly> = current
+        .into_iter()
+        .filter_map(|office| {
+            let prior = previous.get(&office.office_id)?;
+            let current_revenue = office.revenue.filter(|r| *r > 0.0)?;
+            let previous_revenue = prior.revenue.filter(|r| *r > 0.0)?;
+            let current_expense_percent = office.percent_of_revenue(office.total_expenses)?;
+            let previous_expense_percent = prior.percent_of_revenue(prior.total_expenses)?;
+            
+            let expense_percent_delta = current_expense_percent - previous_expense_percent;
+            let revenue_change_percent = (current_revenue - previous_revenue) / previous_revenue * 100.0;
+            
+            if expense_percent_delta <= threshold || revenue_change_percent.abs() > revenue_tolerance {
+                return None;
+            }
+            
+            Some(ExpenseAnomaly {
+                office_id: office.office_id,
+                office_name: office.office_name,
+                current_expense_percent,
+                previous_expense_percent,
+                expense_percent_delta,
+                current_revenue,
+                previous_revenue,
+                revenue_change_percent,
+            })
+        })
+        .collect();
+    
+    anomalies.sort_by(|a, b| {
+        b.expense_percent_delta.partial_cmp(&a.expense_percent_delta).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    
+    Ok(anomalies)
+}
+
+// Alert cutoffs; a value at or above `*_critical` raises a critical alert,
+// at or above `*_warning` a warning. Each field is persisted in `settings` under
+// its own name as a JSON number; missing keys fall back to the defaults below:
+//   lab_exp_percent_warning 20, lab_exp_percent_critical 25,
+//   overtime_percent_warning 3, overtime_percent_critical 5,
+//   backlog_warning 50, backlog_critical 75
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertThresholds {
+    pub lab_exp_percent_warning: f64,
+    pub lab_exp_percent_critical: f64,
+    pub overtime_percent_warning: f64,
+    pub overtime_percent_critical: f64,
+    pub backlog_warning: f64,
+    pub backlog_critical: f64,
+}
+
+impl Default for AlertThresholds {
+    fn default() -> Self {
+        AlertThresholds {
+            lab_exp_percent_warning: 20.0,
+            lab_exp_percent_critical: 25.0,
+            overtime_percent_warning: 3.0,
+            overtime_percent_critical: 5.0,
+            backlog_warning: 50.0,
+            backlog_critical: 75.0,
+        }
+    }
+}
+
+impl AlertThresholds {
+    fn load(conn: &Connection) -> Self {
+        let defaults = AlertThresholds::default();
+        AlertThresholds {
+            lab_exp_percent_warning: setting_f64(conn, "lab_exp_percent_warning", defaults.lab_exp_percent_warning),
+            lab_exp_percent_critical: setting_f64(conn, "lab_exp_percent_critical", defaults.lab_exp_percent_critical),
+            overtime_percent_warning: setting_f64(conn, "overtime_percent_warning", defaults.overtime_percent_warning),
+            overtime_percent_critical: setting_f64(conn, "overtime_percent_critical", defaults.overtime_percent_critical),
+            backlog_warning: setting_f64(conn, "backlog_warning", defaults.backlog_warning),
+            backlog_critical: setting_f64(conn, "backlog_critical", defaults.backlog_critical),
+        }
+    }
+    
+    fn entries(&self) -> [(&'static str, f64); 6] {
+        [
+            ("lab_exp_percent_warning", self.lab_exp_percent_warning),
+            ("lab_exp_percent_critical", self.lab_exp_percent_critical),
+            ("overtime_percent_warning", self.overtime_percent_warning),
+            ("overtime_percent_critical", self.overtime_percent_critical),
+            ("backlog_warning", self.backlog_warning),
+            ("backlog_critical", self.backlog_critical),
+        ]
+    }
+}
+
+// Insert any alerts an office's month breaches; skips alert types already raised
+// for that office/period. Returns the number of alerts created.
+fn evaluate_office_alerts(
+    conn: &Connection,
+    office_id: i64,
+    year: i32,
+    month: i32,
+    thresholds: &AlertThresholds,
+) -> Result<usize, LabPulseError> {
+    let financials = conn.query_row(
+        "SELECT revenue, lab_exp_with_outside, overtime_exp FROM monthly_financials
+         WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| {
+            Ok((
+                row.get::<_, Option<f64>>(0)?,
+                row.get::<_, Option<f64>>(1)?,
+                row.get::<_, Option<f64>>(2)?,
+            ))
+        },
+    );
+    
+    let (lab_exp_percent, overtime_percent) = match financials {
+        Ok((Some(rev), lab, ot)) if rev > 0.0 => (
+            lab.map(|v| v / rev * 100.0),
+            ot.map(|v| v / rev * 100.0),
+        ),
+        Ok(_) | Err(rusqlite::Error::QueryReturnedNoRows) => (None, None),
+        Err(e) => return Err(e.into()),
+    };
+    
+    let backlog: Option<f64> = match conn.query_row(
+        "SELECT backlog_case_count FROM monthly_ops
+         WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+        |row| row.get::<_, Option<i64>>(0),
+    ) {
+        Ok(count) => count.map(|c| c as f64),
+        Err(rusqlite::Error::QueryReturnedNoRows) => None,
+        Err(e) => return Err(e.into()),
+    };
+    
+    let checks = [
+        (
+            "lab_expense_high",
+            lab_exp_percent,
+            thresholds.lab_exp_percent_warning,
+            thresholds.lab_exp_percent_critical,
+        ),
+        (
+            "overtime_high",
+            overtime_percent,
+            thresholds.overtime_percent_warning,
+            thresholds.overtime_percent_critical,
+        ),
+        (
+            "backlog_high",
+            backlog,
+            thresholds.backlog_warning,
+            thresholds.backlog_critical,
+        ),
+    ];
+    
+    let mut created = 0;
+    for (alert_type, value, warning, critical) in checks {
+        let value = match value {
+            Some(v) if v >= warning => v,
+            _ => continue,
+        };
+        
+        let (severity, threshold) = if value >= critical {
+            ("critical", critical)
+        } else {
+            ("warning", warning)
+        };
+        
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM alerts
+             WHERE office_id = ?1 AND year = ?2 AND month = ?3 AND alert_type = ?4)",
+            params![office_id, year, month, alert_type],
+            |row| row.get(0),
+        )?;
+        
+        if exists {
+            continue;
+        }
+        
+        let message = match alert_type {
+            "lab_expense_high" => format!(
+                "Lab expense is {:.1}% of revenue (threshold {:.1}%)", value, threshold
+            ),
+            "overtime_high" => format!(
+                "Overtime is {:.1}% of revenue (threshold {:.1}%)", value, threshold
+            ),
+            _ => format!("Backlog is {:.0} cases (threshold {:.0})", value, threshold),
+        };
+        
+        conn.execute(
+            "INSERT INTO alerts (office_id, year, month, alert_type, severity, message)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![office_id, year, month, alert_type, severity, message],
+        )?;
+        
+        created += 1;
+    }
+    
+    Ok(created)
+}
+
+// Evaluate one office's financials and ops for the month and raise threshold alerts
+#[tauri::command]
+pub fn generate_alerts(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<usize, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let thresholds = AlertThresholds::load(&conn);
+    evaluate_office_alerts(&conn, office_id, year, month, &thresholds)
+}
+
+// Raise threshold alerts for every office for the month in one transaction.
+// Offices have no archived flag, so every office in the table is evaluated.
+#[tauri::command]
+pub fn generate_all_alerts(db: State<DbConnection>, year: i32, month: i32) -> Result<usize, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let thresholds = AlertThresholds::load(&conn);
+    
+    let mut stmt = conn.prepare("SELECT office_id FROM offices ORDER BY office_id")?;
+    let office_ids: Vec<i64> = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let tx = conn.unchecked_transaction()?;
+    
+    let mut created = 0;
+    for office_id in office_ids {
+        created += evaluate_office_alerts(&tx, office_id, year, month, &thresholds)?;
+    }
+    
+    tx.commit()?;
+    
+    Ok(created)
+}
+
+// Saved filter view
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SavedView {
+    pub id: i64,
+    pub name: String,
+    pub filters: serde_json::Value,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+fn saved_view_from_row(row: &rusqlite::Row) -> rusqlite::Result<SavedView> {
+    let filters_json: String = row.get(2)?;
+    Ok(SavedView {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        filters: serde_json::from_str(&filters_json).unwrap_or(serde_json::Value::Null),
+        created_at: row.get(3)?,
+        updated_at: row.get(4)?,
+    })
+}
+
+// Save (or overwrite by name) a filter view; filters_json must be a JSON object
+#[tauri::command]
+pub fn save_view(
+    db: State<DbConnection>,
+    name: String,
+    filters_json: String,
+) -> Result<i64, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(LabPulseError::Validation("View name is required".to_string()));
+    }
+    
+    let filters: serde_json::Value = serde_json::from_str(&filters_json)
+        .map_err(|e| LabPulseError::Validation(format!("Invalid filters JSON: {}", e)))?;
+    if !filters.is_object() {
+        return Err(LabPulseError::Validation("Filters must be a JSON object".to_string()));
+    }
+    
+    conn.execute(
+        "INSERT INTO saved_views (name, filters_json)
+         VALUES (?1, ?2)
+         ON CONFLICT(name) DO UPDATE SET
+             filters_json = excluded.filters_json,
+             updated_at = CURRENT_TIMESTAMP",
+        params![name, filters.to_string()],
+    )?;
+    
+    conn.query_row(
+        "SELECT id FROM saved_views WHERE name = ?1",
+        params![name],
+        |row| row.get(0),
+    ).map_err(LabPulseError::from)
+}
+
+// List saved views alphabetically
+#[tauri::command]
+pub fn list_views(db: State<DbConnection>) -> Result<Vec<SavedView>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT id, name, filters_json, created_at, updated_at FROM saved_views ORDER BY name"
+    )?;
+    
+    let views = stmt.query_map([], saved_view_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(views)
+}
+
+// Get a saved view by id
+#[tauri::command]
+pub fn get_view(db: State<DbConnection>, id: i64) -> Result<Option<SavedView>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let result = conn.query_row(
+        "SELECT id, name, filters_json, created_at, updated_at FROM saved_views WHERE id = ?1",
+        params![id],
+        saved_view_from_row,
+    );
+    
+    match result {
+        Ok(view) => Ok(Some(view)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// Delete a saved view by id
+#[tauri::command]
+pub fn delete_view(db: State<DbConnection>, id: i64) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let deleted = conn.execute("DELETE FROM saved_views WHERE id = ?1", params![id])?;
+    
+    if deleted == 0 {
+        return Err(LabPulseError::NotFound(format!("Saved view {} not found", id)));
+    }
+    
+    Ok(())
+}
+
+// Alert row joined with its office name
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Alert {
+    pub id: i64,
+    pub office_id: i64,
+    pub office_name: String,
+    pub year: i32,
+    pub month: i32,
+    pub alert_type: String,
+    pub severity: Option<String>,
+    pub message: String,
+    pub is_dismissed: bool,
+    pub created_at: Option<String>,
+}
+
+// Get alerts for a period, critical first, then by office
+#[tauri::command]
+pub fn get_alerts(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    include_dismissed: bool,
+) -> Result<Vec<Alert>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT a.id, a.office_id, o.office_name, a.year, a.month, a.alert_type,
+                a.severity, a.message, COALESCE(a.is_dismissed, 0), a.created_at
+         FROM alerts a
+         JOIN offices o ON o.office_id = a.office_id
+         WHERE a.year = ?1 AND a.month = ?2
+           AND (?3 = 1 OR COALESCE(a.is_dismissed, 0) = 0)
+         ORDER BY CASE a.severity WHEN 'critical' THEN 0 WHEN 'warning' THEN 1 ELSE 2 END,
+                  a.office_id, a.id"
+    )?;
+    
+    let alerts = stmt.query_map(params![year, month, include_dismissed], |row| {
+        Ok(Alert {
+            id: row.get(0)?,
+            office_id: row.get(1)?,
+            office_name: row.get(2)?,
+            year: row.get(3)?,
+            month: row.get(4)?,
+            alert_type: row.get(5)?,
+            severity: row.get(6)?,
+            message: row.get(7)?,
+            is_dismissed: row.get::<_, i64>(8)? != 0,
+            created_at: row.get(9)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(alerts)
+}
+
+// Every (year, month) from start to end inclusive, rolling over year boundaries
+fn month_range(start_year: i32, start_month: i32, end_year: i32, end_month: i32) -> Vec<(i32, i32)> {
+    let mut periods = Vec::new();
+    let (mut y, mut m) = (start_year, start_month);
+    while y < end_year || (y == end_year && m <= end_month) {
+        periods.push((y, m));
+        m += 1;
+        if m > 12 {
+            m = 1;
+            y += 1;
+        }
+    }
+    periods
+}
+
+fn validate_month_range(start_year: i32, start_month: i32, end_year: i32, end_month: i32) -> Result<(), LabPulseError> {
+    validate_month(start_month)?;
+    validate_month(end_month)?;
+    if (start_year, start_month) > (end_year, end_month) {
+        return Err(LabPulseError::Validation("Start period must not be after end period".to_string()));
+    }
+    Ok(())
+}
+
+// Company-wide value for one month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthValue {
+    pub year: i32,
+    pub month: i32,
+    pub value: f64,
+    pub percent_of_lab_expense: Option<f64>,
+    pub offices_reporting: i64,
+}
+
+// Company outside-lab spend per month (recomputed as lab_exp_with_outside - lab_exp_no_outside)
+// and its share of total lab expense
+#[tauri::command]
+pub fn get_company_outside_lab_trend(
+    db: State<DbConnection>,
+    start_year: i32,
+    start_month: i32,
+    end_year: i32,
+    end_month: i32,
+) -> Result<Vec<MonthValue>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month_range(start_year, start_month, end_year, end_month)?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT year, month,
+                SUM(COALESCE(lab_exp_with_outside, 0) - COALESCE(lab_exp_no_outside, 0)),
+                SUM(COALESCE(lab_exp_with_outside, 0)),
+                COUNT(*)
+         FROM monthly_financials
+         WHERE (year * 100 + month) BETWEEN (?1 * 100 + ?2) AND (?3 * 100 + ?4)
+         GROUP BY year, month"
+    )?;
+    
+    let totals: HashMap<(i32, i32), (f64, f64, i64)> = stmt
+        .query_map(params![start_year, start_month, end_year, end_month], |row| {
+            Ok(((row.get(0)?, row.get(1)?), (row.get(2)?, row.get(3)?, row.get(4)?)))
+        })?
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    
+    Ok(month_range(start_year, start_month, end_year, end_month)
+        .into_iter()
+        .map(|(year, month)| {
+            let (outside, lab_total, offices_reporting) =
+                totals.get(&(year, month)).copied().unwrap_or((0.0, 0.0, 0));
+            MonthValue {
+                year,
+                month,
+                value: outside,
+                percent_of_lab_expense: if lab_total > 0.0 {
+                    Some(outside / lab_total * 100.0)
+                } else {
+                    None
+                },
+                offices_reporting,
+            }
+        })
+        .collect())
+}
+
+fn set_alert_dismissed(conn: &Connection, alert_id: i64, dismissed: bool) -> Result<(), LabPulseError> {
+    let updated = conn.execute(
+        "UPDATE alerts
+         SET is_dismissed = ?1,
+             dismissed_at = CASE WHEN ?1 THEN CURRENT_TIMESTAMP ELSE NULL END
+         WHERE id = ?2",
+        params![dismissed, alert_id],
+    )?;
+    
+    if updated == 0 {
+        return Err(LabPulseError::NotFound(format!("Alert {} no longer exists", alert_id)));
+    }
+    
+    Ok(())
+}
+
+// Dismiss a single alert
+#[tauri::command]
+pub fn dismiss_alert(db: State<DbConnection>, alert_id: i64) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    set_alert_dismissed(&conn, alert_id, true)
+}
+
+// Reopen a previously dismissed alert
+#[tauri::command]
+pub fn reopen_alert(db: State<DbConnection>, alert_id: i64) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    set_alert_dismissed(&conn, alert_id, false)
+}
+
+// Dismiss every open alert for an office/month; returns how many were dismissed
+#[tauri::command]
+pub fn dismiss_all_alerts(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<usize, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    conn.execute(
+        "UPDATE alerts SET is_dismissed = 1, dismissed_at = CURRENT_TIMESTAMP
+         WHERE office_id = ?1 AND year = ?2 AND month = ?3 AND COALESCE(is_dismissed, 0) = 0",
+        params![office_id, year, month],
+    ).map_err(LabPulseError::from)
+}
+
+// How quickly alerts for a period were dismissed, in hours
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResolutionStats {
+    pub year: i32,
+    pub month: i32,
+    pub dismissed_count: i64,
+    pub average_hours: Option<f64>,
+    pub max_hours: Option<f64>,
+}
+
+// Average and longest time between an alert being raised and dismissed for a period.
+// Alerts dismissed before dismissed_at was tracked are ignored.
+#[tauri::command]
+pub fn get_alert_resolution_stats(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<ResolutionStats, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let (dismissed_count, average_hours, max_hours) = conn.query_row(
+        "SELECT COUNT(*),
+                AVG((julianday(dismissed_at) - julianday(created_at)) * 24.0),
+                MAX((julianday(dismissed_at) - julianday(created_at)) * 24.0)
+         FROM alerts
+         WHERE year = ?1 AND month = ?2
+           AND COALESCE(is_dismissed, 0) = 1
+           AND dismissed_at IS NOT NULL",
+        params![year, month],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    )?;
+    
+    Ok(ResolutionStats {
+        year,
+        month,
+        dismissed_count,
+        average_hours,
+        max_hours,
+    })
+}
+
+// Office/year/month key
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Period {
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+}
+
+// Find office/months with weekly_volume data but no monthly_volume rollup
+#[tauri::command]
+pub fn find_missing_rollups(db: State<DbConnection>) -> Result<Vec<Period>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT office_id, year, week_number FROM weekly_volume"
+    )?;
+    
+    let weekly_periods: std::collections::BTreeSet<Period> = stmt
+        .query_map([], |row| {
+            let year = row.get(1)?;
+            Ok(Period {
+                office_id: row.get(0)?,
+                year,
+                month: month_for_week(year, row.get(2)?),
+            })
+        })?
+        .collect::<Result<_, _>>()?;
+    
+    let mut missing = Vec::new();
+    for period in weekly_periods {
+        let has_rollup: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM monthly_volume WHERE office_id = ?1 AND year = ?2 AND month = ?3)",
+            params![period.office_id, period.year, period.month],
+            |row| row.get(0),
+        )?;
+        
+        if !has_rollup {
+            missing.push(period);
+        }
+    }
+    
+    Ok(missing)
+}
+
+// Get the alert thresholds, with defaults filled in for unset keys
+#[tauri::command]
+pub fn get_alert_thresholds(db: State<DbConnection>) -> Result<AlertThresholds, LabPulseError> {
+    let conn = db.0.get()?;
+    Ok(AlertThresholds::load(&conn))
+}
+
+// Persist alert thresholds to the settings table
+#[tauri::command]
+pub fn set_alert_thresholds(
+    db: State<DbConnection>,
+    thresholds: AlertThresholds,
+) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    
+    for (key, value) in thresholds.entries() {
+        if !value.is_finite() || value < 0.0 {
+            return Err(LabPulseError::Validation(format!("{} must be a non-negative number", key)));
+        }
+    }
+    
+    let pairs = [
+        ("lab expense", thresholds.lab_exp_percent_warning, thresholds.lab_exp_percent_critical),
+        ("overtime", thresholds.overtime_percent_warning, thresholds.overtime_percent_critical),
+        ("backlog", thresholds.backlog_warning, thresholds.backlog_critical),
+    ];
+    for (name, warning, critical) in pairs {
+        if warning > critical {
+            return Err(LabPulseError::Validation(format!("The {} warning threshold must not exceed the critical threshold", name)));
+        }
+    }
+    
+    let tx = conn.unchecked_transaction()?;
+    for (key, value) in thresholds.entries() {
+        let json_value = serde_json::to_string(&value)
+            .map_err(|e| LabPulseError::Validation(e.to_string()))?;
+        upsert_setting(&tx, key, &json_value)?;
+    }
+    tx.commit()?;
+    
+    Ok(())
+}
+
+// One calendar month's average revenue relative to the overall monthly average
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthIndex {
+    pub month: i32,
+    pub average_revenue: f64,
+    pub years_of_data: i32,
+    pub index: f64,
+}
+
+// Get the revenue seasonal index for each calendar month; needs two years of data
+#[tauri::command]
+pub fn get_seasonal_index(
+    db: State<DbConnection>,
+    office_id: i64,
+) -> Result<Vec<MonthIndex>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let year_count: i32 = conn.query_row(
+        "SELECT COUNT(DISTINCT year) FROM monthly_financials
+         WHERE office_id = ?1 AND revenue IS NOT NULL",
+        params![office_id],
+        |row| row.get(0),
+    )?;
+    
+    if year_count < 2 {
+        return Err(LabPulseError::Validation(format!(
+            "A seasonal index needs at least two years of revenue; office {} has {}",
+            office_id, year_count
+        )));
+    }
+    
+    let overall_avg: f64 = conn.query_row(
+        "SELECT AVG(revenue) FROM monthly_financials
+         WHERE office_id = ?1 AND revenue IS NOT NULL",
+        params![office_id],
+        |row| row.get(0),
+    )?;
+    
+    if overall_avg == 0.0 {
+        return Err(LabPulseError::Validation("Average monthly revenue is zero; cannot compute a seasonal index".to_string()));
+    }
+    
+    let mut stmt = conn.prepare(
+        "SELECT month, AVG(revenue), COUNT(DISTINCT year)
+         FROM monthly_financials
+         WHERE office_id = ?1 AND revenue IS NOT NULL
+         GROUP BY month
+         ORDER BY month"
+    )?;
+    
+    let indexes = stmt.query_map(params![office_id], |row| {
+        let average_revenue: f64 = row.get(1)?;
+        Ok(MonthIndex {
+            month: row.get(0)?,
+            average_revenue,
+            years_of_data: row.get(2)?,
+            index: average_revenue / overall_avg,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(indexes)
+}
+
+// Normalize an office model, rejecting anything the offices CHECK would refuse
+fn validate_office_model(model: &str) -> Result<String, LabPulseError> {
+    let model = model.trim().to_uppercase();
+    if model != "PO" && model != "PLLC" {
+        return Err(LabPulseError::Validation(format!("Model must be PO or PLLC, got '{}'", model)));
+    }
+    Ok(model)
+}
+
+// Create a new office by hand
+#[tauri::command]
+pub fn create_office(db: State<DbConnection>, office: Office) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let model = validate_office_model(&office.model)?;
+    if office.office_name.trim().is_empty() {
+        return Err(LabPulseError::Validation("Office name is required".to_string()));
+    }
+    
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM offices WHERE office_id = ?1)",
+        params![office.office_id],
+        |row| row.get(0),
+    )?;
+    
+    if exists {
+        return Err(LabPulseError::Validation(format!("Office ID {} already exists", office.office_id)));
+    }
+    
+    conn.execute(
+        "INSERT INTO offices (office_id, office_name, model, address, phone, managing_dentist, dfo, standardization_status)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            office.office_id,
+            office.office_name.trim(),
+            model,
+            office.address,
+            office.phone,
+            office.managing_dentist,
+            office.dfo,
+            office.standardization_status,
+        ],
+    )?;
+    
+    Ok(())
+}
+
+// Update an existing office's details
+#[tauri::command]
+pub fn update_office(db: State<DbConnection>, office: Office) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let model = validate_office_model(&office.model)?;
+    if office.office_name.trim().is_empty() {
+        return Err(LabPulseError::Validation("Office name is required".to_string()));
+    }
+    
+    let updated = conn.execute(
+        "UPDATE offices SET
+            office_name = ?2,
+            model = ?3,
+            address = ?4,
+            phone = ?5,
+            managing_dentist = ?6,
+            dfo = ?7,
+            standardization_status = ?8,
+            updated_at = CURRENT_TIMESTAMP
+         WHERE office_id = ?1",
+        params![
+            office.office_id,
+            office.office_name.trim(),
+            model,
+            office.address,
+            office.phone,
+            office.managing_dentist,
+            office.dfo,
+            office.standardization_status,
+        ],
+    )?;
+    
+    if updated == 0 {
+        return Err(LabPulseError::NotFound(format!("Office {} not found", office.office_id)));
+    }
+    
+    Ok(())
+}
+
+// History rows that go with an office when it is deleted
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OfficeDependents {
+    pub office_id: i64,
+    pub financial_rows: i64,
+    pub ops_rows: i64,
+    pub volume_rows: i64,
+    pub weekly_volume_rows: i64,
+    pub deleted: bool,
+}
+
+// Delete an office and, via ON DELETE CASCADE, its history. With `confirm` false
+// nothing is deleted and only the dependent row counts are returned.
+#[tauri::command]
+pub fn delete_office(
+    db: State<DbConnection>,
+    office_id: i64,
+    confirm: bool,
+) -> Result<OfficeDependents, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM offices WHERE office_id = ?1)",
+        params![office_id],
+        |row| row.get(0),
+    )?;
+    
+    if !exists {
+        return Err(LabPulseError::NotFound(format!("Office {} not found", office_id)));
+    }
+    
+    let count = |table: &str| -> Result<i64, LabPulseError> {
+        conn.query_row(
+            &format!("SELECT COUNT(*) FROM {} WHERE office_id = ?1", table),
+            params![office_id],
+            |row| row.get(0),
+        ).map_err(LabPulseError::from)
+    };
+    
+    let mut dependents = OfficeDependents {
+        office_id,
+        financial_rows: count("monthly_financials")?,
+        ops_rows: count("monthly_ops")?,
+        volume_rows: count("monthly_volume")?,
+        weekly_volume_rows: count("weekly_volume")?,
+        deleted: false,
+    };
+    
+    if confirm {
+        conn.execute("DELETE FROM offices WHERE office_id = ?1", params![office_id])?;
+        dependents.deleted = true;
+    }
+    
+    Ok(dependents)
+}
+
+// Staff roster entry
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Staff {
+    pub staff_id: i64,
+    pub office_id: i64,
+    pub name: String,
+    pub job_title: String,
+    pub hire_date: Option<String>,
+}
+
+// Validate a staff record's text fields and normalize its hire date to ISO
+fn normalize_staff(staff: &Staff) -> Result<(String, String, Option<String>), LabPulseError> {
+    let name = staff.name.trim();
+    let job_title = staff.job_title.trim();
+    if name.is_empty() {
+        return Err(LabPulseError::Validation("Staff name is required".to_string()));
+    }
+    if job_title.is_empty() {
+        return Err(LabPulseError::Validation("Job title is required".to_string()));
+    }
+    
+    let hire_date = match staff.hire_date.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some(raw) => Some(
+            crate::imports::parse_hire_date(raw)
+                .ok_or_else(|| LabPulseError::Validation(format!("Invalid hire date '{}'", raw)))?
+                .format("%Y-%m-%d")
+                .to_string(),
+        ),
+    };
+    
+    Ok((name.to_string(), job_title.to_string(), hire_date))
+}
+
+fn staff_write_error(e: rusqlite::Error, staff: &Staff) -> LabPulseError {
+    match e {
+        rusqlite::Error::SqliteFailure(err, _)
+            if err.code == rusqlite::ErrorCode::ConstraintViolation =>
+        {
+            LabPulseError::Validation(format!(
+                "Could not save '{}': office {} must exist and may not already have staff with that name",
+                staff.name.trim(), staff.office_id
+            ))
+        }
+        e => LabPulseError::from(e),
+    }
+}
+
+// Get an office's staff roster
+#[tauri::command]
+pub fn get_staff(db: State<DbConnection>, office_id: i64) -> Result<Vec<Staff>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT staff_id, office_id, name, job_title, hire_date
+         FROM staff WHERE office_id = ?1
+         ORDER BY name"
+    )?;
+    
+    let staff = stmt.query_map(params![office_id], |row| {
+        Ok(Staff {
+            staff_id: row.get(0)?,
+            office_id: row.get(1)?,
+            name: row.get(2)?,
+            job_title: row.get(3)?,
+            hire_date: row.get(4)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(staff)
+}
+
+// Add a staff member; the incoming staff_id is ignored. Returns the new staff_id.
+#[tauri::command]
+pub fn add_staff(db: State<DbConnection>, staff: Staff) -> Result<i64, LabPulseError> {
+    let conn = db.0.get()?;
+    let (name, job_title, hire_date) = normalize_staff(&staff)?;
+    
+    conn.execute(
+        "INSERT INTO staff (office_id, name, job_title, hire_date) VALUES (?1, ?2, ?3, ?4)",
+        params![staff.office_id, name, job_title, hire_date],
+    ).map_err(|e| staff_write_error(e, &staff))?;
+    
+    Ok(conn.last_insert_rowid())
+}
+
+// Update a staff member's details
+#[tauri::command]
+pub fn update_staff(db: State<DbConnection>, staff: Staff) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    let (name, job_title, hire_date) = normalize_staff(&staff)?;
+    
+    let updated = conn.execute(
+        "UPDATE staff SET office_id = ?2, name = ?3, job_title = ?4, hire_date = ?5
+         WHERE staff_id = ?1",
+        params![staff.staff_id, staff.office_id, name, job_title, hire_date],
+    ).map_err(|e| staff_write_error(e, &staff))?;
+    
+    if updated == 0 {
+        return Err(LabPulseError::NotFound(format!("Staff member {} not found", staff.staff_id)));
+    }
+    
+    Ok(())
+}
+
+// Remove a staff member
+#[tauri::command]
+pub fn delete_staff(db: State<DbConnection>, staff_id: i64) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let deleted = conn.execute("DELETE FROM staff WHERE staff_id = ?1", params![staff_id])?;
+    
+    if deleted == 0 {
+        return Err(LabPulseError::NotFound(format!("Staff member {} not found", staff_id)));
+    }
+    
+    Ok(())
+}
+
+// Fraction of the month-over-month labor cost increase that came from overtime.
+// Labor is personnel + overtime + bonus; returns None when either month is missing
+// or labor did not grow.
+#[tauri::command]
+pub fn get_overtime_share_of_growth(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<Option<f64>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let labor = |y: i32, m: i32| -> Result<Option<(f64, f64)>, LabPulseError> {
+        match conn.query_row(
+            "SELECT COALESCE(personnel_exp, 0) + COALESCE(overtime_exp, 0) + COALESCE(bonus_exp, 0),
+                    COALESCE(overtime_exp, 0)
+             FROM monthly_financials
+             WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+            params![office_id, y, m],
+            |row| Ok((row.get::<_, f64>(0)?, row.get::<_, f64>(1)?)),
+        ) {
+            Ok(values) => Ok(Some(values)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    };
+    
+    let (prev_year, prev_month) = previous_month(year, month);
+    let (current, prior) = match (labor(year, month)?, labor(prev_year, prev_month)?) {
+        (Some(current), Some(prior)) => (current, prior),
+        _ => return Ok(None),
+    };
+    
+    let labor_delta = current.0 - prior.0;
+    if labor_delta <= 0.0 {
+        return Ok(None);
+    }
+    
+    Ok(Some((current.1 - prior.1) / labor_delta))
+}
+
+// Office contact (lab manager or any other role)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Contact {
+    pub contact_id: i64,
+    pub office_id: i64,
+    pub role: String,
+    pub name: String,
+    pub phone: Option<String>,
+}
+
+// Validate a contact's required text fields, returning the trimmed role and name
+fn normalize_contact(contact: &Contact) -> Result<(String, String), LabPulseError> {
+    let role = contact.role.trim();
+    let name = contact.name.trim();
+    if role.is_empty() {
+        return Err(LabPulseError::Validation("Contact role is required".to_string()));
+    }
+    if name.is_empty() {
+        return Err(LabPulseError::Validation("Contact name is required".to_string()));
+    }
+    Ok((role.to_string(), name.to_string()))
+}
+
+// Get an office's contacts
+#[tauri::command]
+pub fn get_contacts(db: State<DbConnection>, office_id: i64) -> Result<Vec<Contact>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT contact_id, office_id, role, name, phone
+         FROM office_contacts WHERE office_id = ?1
+         ORDER BY role, name"
+    )?;
+    
+    let contacts = stmt.query_map(params![office_id], |row| {
+        Ok(Contact {
+            contact_id: row.get(0)?,
+            office_id: row.get(1)?,
+            role: row.get(2)?,
+            name: row.get(3)?,
+            phone: row.get(4)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(contacts)
+}
+
+// Add a contact with any role; the incoming contact_id is ignored. Returns the new contact_id.
+#[tauri::command]
+pub fn add_contact(db: State<DbConnection>, contact: Contact) -> Result<i64, LabPulseError> {
+    let conn = db.0.get()?;
+    let (role, name) = normalize_contact(&contact)?;
+    
+    conn.execute(
+        "INSERT INTO office_contacts (office_id, role, name, phone) VALUES (?1, ?2, ?3, ?4)",
+        params![contact.office_id, role, name, contact.phone],
+    )?;
+    
+    Ok(conn.last_insert_rowid())
+}
+
+// Update a contact's details
+#[tauri::command]
+pub fn update_contact(db: State<DbConnection>, contact: Contact) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    let (role, name) = normalize_contact(&contact)?;
+    
+    let updated = conn.execute(
+        "UPDATE office_contacts SET office_id = ?2, role = ?3, name = ?4, phone = ?5
+         WHERE contact_id = ?1",
+        params![contact.contact_id, contact.office_id, role, name, contact.phone],
+    )?;
+    
+    if updated == 0 {
+        return Err(LabPulseError::NotFound(format!("Contact {} not found", contact.contact_id)));
+    }
+    
+    Ok(())
+}
+
+// Remove a contact
+#[tauri::command]
+pub fn delete_contact(db: State<DbConnection>, contact_id: i64) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let deleted = conn.execute("DELETE FROM office_contacts WHERE contact_id = ?1", params![contact_id])?;
+    
+    if deleted == 0 {
+        return Err(LabPulseError::NotFound(format!("Contact {} not found", contact_id)));
+    }
+    
+    Ok(())
+}
+
+// Rows of the comparison matrix: (label, OFFICE_METRICS key, number format)
+const COMPARISON_MATRIX_ROWS: [(&str, &str, &str); 6] = [
+    ("Revenue", "revenue", "$#,##0"),
+    ("Lab %", "lab_exp_percent", "0.0"),
+    ("Personnel %", "personnel_percent", "0.0"),
+    ("Overtime %", "overtime_percent", "0.0"),
+    ("Backlog", "backlog_count", "0"),
+    ("Margin %", "margin_percent", "0.0"),
+];
+
+// Export a metrics-by-office matrix for one month to an Excel workbook
+#[tauri::command]
+pub fn export_comparison_matrix_xlsx(
+    db: State<DbConnection>,
+    office_ids: Vec<i64>,
+    year: i32,
+    month: i32,
+    output_path: String,
+) -> Result<String, LabPulseError> {
+    use rust_xlsxwriter::{Format, Workbook};
+    
+    let conn = db.0.get()?;
+    
+    if office_ids.is_empty() {
+        return Err(LabPulseError::Validation("Select at least one office to compare".to_string()));
+    }
+    validate_month(month)?;
+    
+    let metrics = load_office_month_metrics(&conn, year, month)?;
+    let by_id: HashMap<i64, &OfficeMonthMetrics> =
+        metrics.iter().map(|m| (m.office_id, m)).collect();
+    
+    let columns = office_ids
+        .iter()
+        .map(|id| by_id.get(id).copied().ok_or_else(|| LabPulseError::NotFound(format!("Office {} not found", id))))
+        .collect::<Result<Vec<_>, LabPulseError>>()?;
+    
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name(format!("{}-{:02}", year, month))?;
+    
+    let bold = Format::new().set_bold();
+    sheet.write_string_with_format(0, 0, "Metric", &bold)?;
+    sheet.set_column_width(0, 14)?;
+    
+    for (col, office) in columns.iter().enumerate() {
+        let col = col as u16 + 1;
+        let header = format!("{} ({})", office.office_name, office.office_id);
+        sheet.write_string_with_format(0, col, &header, &bold)?;
+        sheet.set_column_width(col, 18)?;
+    }
+    
+    for (row, (label, key, num_format)) in COMPARISON_MATRIX_ROWS.iter().enumerate() {
+        let row = row as u32 + 1;
+        let format = Format::new().set_num_format(*num_format);
+        sheet.write_string_with_format(row, 0, *label, &bold)?;
+        
+        for (col, office) in columns.iter().enumerate() {
+            if let Some(value) = office.value(key) {
+                sheet.write_number_with_format(row, col as u16 + 1, value, &format)?;
+            }
+        }
+    }
+    
+    workbook.save(&output_path)
+        .map_err(|e| LabPulseError::Io(format!("Failed to write workbook: {}", e)))?;
+    
+    Ok(output_path)
+}
+
+// Get a single setting value, or None if it has never been set
+#[tauri::command]
+pub fn get_setting(db: State<DbConnection>, key: String) -> Result<Option<String>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    match conn.query_row(
+        "SELECT value FROM settings WHERE key = ?1",
+        params![key],
+        |row| row.get(0),
+    ) {
+        Ok(value) => Ok(Some(value)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// Create or overwrite a setting
+#[tauri::command]
+pub fn set_setting(db: State<DbConnection>, key: String, value: String) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(LabPulseError::Validation("Setting key is required".to_string()));
+    }
+    
+    upsert_setting(&conn, key, &value)?;
+    Ok(())
+}
+
+// Get every stored setting keyed by name
+#[tauri::command]
+pub fn get_all_settings(db: State<DbConnection>) -> Result<HashMap<String, String>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare("SELECT key, value FROM settings")?;
+    
+    let settings = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<HashMap<String, String>, _>>()?;
+    
+    Ok(settings)
+}
+
+// Count consecutive months, ending at the given period, where the metric breached
+// the threshold ("above" or "below"). Stops at the first non-breach or missing month.
+#[tauri::command]
+pub fn get_breach_streak(
+    db: State<DbConnection>,
+    office_id: i64,
+    metric: String,
+    threshold: f64,
+    direction: String,
+    through_year: i32,
+    through_month: i32,
+) -> Result<i32, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_metric(&metric)?;
+    validate_month(through_month)?;
+    let above = match direction.as_str() {
+        "above" => true,
+        "below" => false,
+        other => return Err(LabPulseError::Validation(format!("Unknown direction: {} (expected above or below)", other))),
+    };
+    
+    let mut streak = 0;
+    let (mut year, mut month) = (through_year, through_month);
+    loop {
+        let value = load_single_office_month_metrics(&conn, office_id, year, month)?
+            .ok_or_else(|| LabPulseError::NotFound(format!("Office {} not found", office_id)))?
+            .value(&metric);
+        
+        let breached = match value {
+            Some(v) if above => v > threshold,
+            Some(v) => v < threshold,
+            None => false,
+        };
+        if !breached {
+            break;
+        }
+        
+        streak += 1;
+        (year, month) = previous_month(year, month);
+    }
+    
+    Ok(streak)
+}
+
+// One row of import history
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportLogEntry {
+    pub id: i64,
+    pub import_type: String,
+    pub filename: Option<String>,
+    pub rows_processed: Option<i64>,
+    pub rows_inserted: Option<i64>,
+    pub rows_updated: Option<i64>,
+    pub warnings: Vec<String>,
+    pub imported_at: Option<String>,
+}
+
+// Get import history, newest first; all entries when no limit is given
+#[tauri::command]
+pub fn get_import_log(
+    db: State<DbConnection>,
+    limit: Option<i64>,
+) -> Result<Vec<ImportLogEntry>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT id, import_type, filename, rows_processed, rows_inserted, rows_updated,
+                warnings, imported_at
+         FROM import_log
+         ORDER BY imported_at DESC, id DESC
+         LIMIT ?1"
+    )?;
+    
+    let entries = stmt.query_map(params![limit.unwrap_or(-1)], |row| {
+        let warnings_json: Option<String> = row.get(6)?;
+        Ok(ImportLogEntry {
+            id: row.get(0)?,
+            import_type: row.get(1)?,
+            filename: row.get(2)?,
+            rows_processed: row.get(3)?,
+            rows_inserted: row.get(4)?,
+            rows_updated: row.get(5)?,
+            warnings: warnings_json
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default(),
+            imported_at: row.get(7)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(entries)
+}
+
+// Summed monthly_financials for one office, or every office, over a span of
+// months, with ratios computed from the sums (not averaged from monthly percents).
+// months_reported counts office-month rows, so it is the office count when the
+// span is a single month.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FinancialTotals {
+    pub months_reported: i32,
+    pub revenue: f64,
+    pub lab_exp_no_outside: f64,
+    pub lab_exp_with_outside: f64,
+    pub outside_lab_spend: f64,
+    pub teeth_supplies: f64,
+    pub lab_supplies: f64,
+    pub lab_hub: f64,
+    pub lss_expense: f64,
+    pub personnel_exp: f64,
+    pub overtime_exp: f64,
+    pub bonus_exp: f64,
+    pub total_expenses: f64,
+    pub lab_exp_percent: Option<f64>,
+    pub personnel_percent: Option<f64>,
+    pub overtime_percent: Option<f64>,
+    pub margin_percent: Option<f64>,
+}
+
+// Sum an office's financials from (start_year, start_month) through
+// (end_year, end_month) inclusive; every office when office_id is None
+fn sum_financials(
+    conn: &Connection,
+    office_id: Option<i64>,
+    start_year: i32,
+    start_month: i32,
+    end_year: i32,
+    end_month: i32,
+) -> rusqlite::Result<FinancialTotals> {
+    let sums: Vec<f64> = conn.query_row(
+        &format!(
+            "SELECT COUNT(*),
+                    COALESCE(SUM(revenue), 0), COALESCE(SUM(lab_exp_no_outside), 0),
+                    COALESCE(SUM(lab_exp_with_outside), 0), COALESCE(SUM(outside_lab_spend), 0),
+                    COALESCE(SUM(teeth_supplies), 0), COALESCE(SUM(lab_supplies), 0),
+                    COALESCE(SUM(lab_hub), 0), COALESCE(SUM(lss_expense), 0),
+                    COALESCE(SUM(personnel_exp), 0), COALESCE(SUM(overtime_exp), 0),
+                    COALESCE(SUM(bonus_exp), 0), COALESCE(SUM({}), 0)
+             FROM monthly_financials
+             WHERE (?1 IS NULL OR office_id = ?1)
+               AND (year * 100 + month) BETWEEN (?2 * 100 + ?3) AND (?4 * 100 + ?5)",
+            total_expense_sql("")
+        ),
+        params![office_id, start_year, start_month, end_year, end_month],
+        |row| (0..13).map(|i| row.get::<_, f64>(i)).collect(),
+    )?;
+    
+    let revenue = sums[1];
+    let percent = |value: f64| (revenue > 0.0).then(|| value / revenue * 100.0);
+    
+    Ok(FinancialTotals {
+        months_reported: sums[0] as i32,
+        revenue,
+        lab_exp_no_outside: sums[2],
+        lab_exp_with_outside: sums[3],
+        outside_lab_spend: sums[4],
+        teeth_supplies: sums[5],
+        lab_supplies: sums[6],
+        lab_hub: sums[7],
+        lss_expense: sums[8],
+        personnel_exp: sums[9],
+        overtime_exp: sums[10],
+        bonus_exp: sums[11],
+        total_expenses: sums[12],
+        lab_exp_percent: percent(sums[3]),
+        personnel_percent: percent(sums[9]),
+        overtime_percent: percent(sums[10]),
+        margin_percent: percent(sums[12]).map(|expense_pct| 100.0 - expense_pct),
+    })
+}
+
+// Year-to-date totals for one office (months 1..=through_month)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct YtdTotals {
+    pub year: i32,
+    pub through_month: i32,
+    #[serde(flatten)]
+    pub totals: FinancialTotals,
+}
+
+fn ytd_totals(
+    conn: &Connection,
+    office_id: i64,
+    year: i32,
+    through_month: i32,
+) -> rusqlite::Result<YtdTotals> {
+    Ok(YtdTotals {
+        year,
+        through_month,
+        totals: sum_financials(conn, Some(office_id), year, 1, year, through_month)?,
+    })
+}
+
+// This year's YTD against the same months last year
+#[derive(Debug, Serialize, Deserialize)]
+pub struct YtdComparison {
+    pub office_id: i64,
+    pub through_month: i32,
+    pub current: YtdTotals,
+    pub prior: YtdTotals,
+    pub revenue_delta: f64,
+    pub revenue_change_percent: Option<f64>,
+    pub lab_exp_percent_delta: Option<f64>,
+    pub personnel_percent_delta: Option<f64>,
+    pub overtime_percent_delta: Option<f64>,
+    pub margin_percent_delta: Option<f64>,
+}
+
+// Compare an office's YTD revenue and expense ratios with the prior year's same span.
+// Ratio deltas are in percentage points.
+#[tauri::command]
+pub fn get_ytd_vs_prior(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    through_month: i32,
+) -> Result<YtdComparison, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(through_month)?;
+    
+    let current = ytd_totals(&conn, office_id, year, through_month)?;
+    let prior = ytd_totals(&conn, office_id, year - 1, through_month)?;
+    
+    let delta = |a: Option<f64>, b: Option<f64>| match (a, b) {
+        (Some(a), Some(b)) => Some(a - b),
+        _ => None,
+    };
+    
+    Ok(YtdComparison {
+        office_id,
+        through_month,
+        revenue_delta: current.totals.revenue - prior.totals.revenue,
+        revenue_change_percent: (prior.totals.revenue > 0.0)
+            .then(|| (current.totals.revenue - prior.totals.revenue) / prior.totals.revenue * 100.0),
+        lab_exp_percent_delta: delta(current.totals.lab_exp_percent, prior.totals.lab_exp_percent),
+        personnel_percent_delta: delta(current.totals.personnel_percent, prior.totals.personnel_percent),
+        overtime_percent_delta: delta(current.totals.overtime_percent, prior.totals.overtime_percent),
+        margin_percent_delta: delta(current.totals.margin_percent, prior.totals.margin_percent),
+        current,
+        prior,
+    })
+}
+
+// Quarter totals for one office
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QuarterlyFinancials {
+    pub office_id: i64,
+    pub year: i32,
+    pub quarter: i32,
+    #[serde(flatten)]
+    pub totals: FinancialTotals,
+}
+
+// Sum the three months of a quarter (1-4) for regional reviews
+#[tauri::command]
+pub fn get_quarterly_financials(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    quarter: i32,
+) -> Result<QuarterlyFinancials, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_quarter(quarter)?;
+    
+    let start_month = (quarter - 1) * 3 + 1;
+    let totals = sum_financials(&conn, Some(office_id), year, start_month, year, start_month + 2)?;
+    
+    Ok(QuarterlyFinancials {
+        office_id,
+        year,
+        quarter,
+        totals,
+    })
+}
+
+// Export columns for monthly financials. The first 14 match the layout
+// import_bulk_financials reads (outside_lab_spend is ignored on import), so an
+// exported file can be edited and re-imported; the percent columns are derived.
+const FINANCIALS_EXPORT_COLUMNS: [&str; 16] = [
+    "office_id", "year", "month", "revenue", "lab_exp_no_outside", "lab_exp_with_outside",
+    "teeth_supplies", "lab_supplies", "lab_hub", "lss_expense", "personnel_exp",
+    "overtime_exp", "bonus_exp", "outside_lab_spend", "lab_exp_percent", "personnel_percent",
+];
+
+// Export a year of monthly financials (one row per office per month) to Excel
+#[tauri::command]
+pub fn export_financials(
+    db: State<DbConnection>,
+    year: i32,
+    file_path: String,
+) -> Result<String, LabPulseError> {
+    use rust_xlsxwriter::{Format, Workbook};
+    
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT office_id, year, month, revenue, lab_exp_no_outside, lab_exp_with_outside,
+                teeth_supplies, lab_supplies, lab_hub, lss_expense, personnel_exp,
+                overtime_exp, bonus_exp, outside_lab_spend
+         FROM monthly_financials
+         WHERE year = ?1
+         ORDER BY office_id, month"
+    )?;
+    
+    let rows: Vec<Vec<Option<f64>>> = stmt.query_map(params![year], |row| {
+        (0..14).map(|i| row.get::<_, Option<f64>>(i)).collect()
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    if rows.is_empty() {
+        return Err(LabPulseError::NotFound(format!("No financial data found for {}", year)));
+    }
+    
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    // Same sheet name import_bulk_financials looks for
+    sheet.set_name("monthly_financials")?;
+    
+    let bold = Format::new().set_bold();
+    for (col, header) in FINANCIALS_EXPORT_COLUMNS.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, *header, &bold)?;
+    }
+    
+    for (idx, values) in rows.iter().enumerate() {
+        let row = idx as u32 + 1;
+        
+        let revenue = values[3].filter(|rev| *rev > 0.0);
+        let percent = |value: Option<f64>| revenue.zip(value).map(|(rev, v)| v / rev * 100.0);
+        let lab_exp_percent = percent(values[5]);
+        let personnel_percent = percent(values[10]);
+        
+        let cells = values.iter().copied().chain([lab_exp_percent, personnel_percent]);
+        for (col, value) in cells.enumerate() {
+            if let Some(value) = value {
+                sheet.write_number(row, col as u16, value)?;
+            }
+        }
+    }
+    
+    workbook.save(&file_path)
+        .map_err(|e| LabPulseError::Io(format!("Failed to write workbook: {}", e)))?;
+    
+    Ok(file_path)
+}
+
+// Office count for one standardization status
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusCount {
+    pub status: String,
+    pub count: i64,
+    pub percent: f64,
+}
+
+// Standardization rollout across offices
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StandardizationProgress {
+    pub statuses: Vec<StatusCount>,
+    pub total_offices: i64,
+    pub active_offices: i64,
+    pub standardized_offices: i64,
+    pub percent_complete: f64,
+}
+
+// Status an office reaches once standardization is done
+const STANDARDIZED_STATUS: &str = "Standardized";
+
+// Get office counts by standardization status and the share of active offices
+// that are standardized. Offices whose status mentions "inactive" are treated as
+// archived, matching the directory page, and excluded from percent_complete.
+#[tauri::command]
+pub fn get_standardization_progress(db: State<DbConnection>) -> Result<StandardizationProgress, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT COALESCE(NULLIF(TRIM(standardization_status), ''), 'Unspecified') AS status,
+                COUNT(*)
+         FROM offices
+         GROUP BY status
+         ORDER BY COUNT(*) DESC, status"
+    )?;
+    
+    let groups: Vec<(String, i64)> = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let total_offices: i64 = groups.iter().map(|(_, count)| count).sum();
+    let active_offices: i64 = groups
+        .iter()
+        .filter(|(status, _)| !status.to_lowercase().contains("inactive"))
+        .map(|(_, count)| count)
+        .sum();
+    let standardized_offices: i64 = groups
+        .iter()
+        .filter(|(status, _)| status.eq_ignore_ascii_case(STANDARDIZED_STATUS))
+        .map(|(_, count)| count)
+        .sum();
+    
+    let percent_of = |count: i64, total: i64| {
+        if total > 0 { count as f64 / total as f64 * 100.0 } else { 0.0 }
+    };
+    
+    Ok(StandardizationProgress {
+        statuses: groups
+            .into_iter()
+            .map(|(status, count)| StatusCount {
+                percent: percent_of(count, total_offices),
+                status,
+                count,
+            })
+            .collect(),
+        total_offices,
+        active_offices,
+        standardized_offices,
+        percent_complete: percent_of(standardized_offices, active_offices),
+    })
+}
+
+// Export the single-month dashboard to CSV. Returns the number of office rows written.
+#[tauri::command]
+pub fn export_dashboard_csv(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    file_path: String,
+) -> Result<usize, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let summaries = build_dashboard(&conn, year, month, year, month)?;
+    
+    let number = |value: Option<f64>| value.map(|v| format!("{:.2}", v)).unwrap_or_default();
+    
+    let mut csv = String::from(
+        "office_name,model,dfo,revenue,lab_exp_percent,personnel_percent,overtime_percent,backlog_count,has_financial,has_operations,has_volume,has_notes\n"
+    );
+    for summary in &summaries {
+        let fields = [
+            summary.office_name.clone(),
+            summary.model.clone(),
+            summary.dfo.clone().unwrap_or_default(),
+            number(summary.revenue),
+            number(summary.lab_exp_percent),
+            number(summary.personnel_percent),
+            number(summary.overtime_percent),
+            summary.backlog_count.map(|v| v.to_string()).unwrap_or_default(),
+            summary.has_financial.to_string(),
+            summary.has_operations.to_string(),
+            summary.has_volume.to_string(),
+            summary.has_notes.to_string(),
+        ];
+        let line = fields.iter().map(|f| csv_escape(f)).collect::<Vec<_>>().join(",");
+        csv.push_str(&line);
+        csv.push('\n');
+    }
+    
+    std::fs::write(&file_path, csv)
+        .map_err(|e| LabPulseError::Io(format!("Failed to write CSV file: {}", e)))?;
+    
+    Ok(summaries.len())
+}
+
+// Set one baseline value for an office's scenario and calendar month
+#[tauri::command]
+pub fn set_baseline(
+    db: State<DbConnection>,
+    office_id: i64,
+    scenario_name: String,
+    month: i32,
+    field: String,
+    value: f64,
+) -> Result<(), LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let scenario_name = scenario_name.trim();
+    if scenario_name.is_empty() {
+        return Err(LabPulseError::Validation("Scenario name is required".to_string()));
+    }
+    validate_month(month)?;
+    if !ADJUSTABLE_FINANCIAL_FIELDS.contains(&field.as_str()) {
+        return Err(LabPulseError::Validation(format!(
+            "Unknown financial field: {} (expected one of {})",
+            field,
+            ADJUSTABLE_FINANCIAL_FIELDS.join(", ")
+        )));
+    }
+    
+    conn.execute(
+        "INSERT INTO financial_baselines (office_id, scenario_name, month, field, value)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(office_id, scenario_name, month, field) DO UPDATE SET
+            value = excluded.value,
+            updated_at = CURRENT_TIMESTAMP",
+        params![office_id, scenario_name, month, field, value],
+    )?;
+    
+    Ok(())
+}
+
+// Actual vs baseline for one financial field
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BaselineVariance {
+    pub field: String,
+    pub baseline: f64,
+    pub actual: Option<f64>,
+    pub variance: Option<f64>,
+}
+
+// Get actual-minus-baseline for every field the scenario sets for that month
+#[tauri::command]
+pub fn get_variance_to_baseline(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+    scenario_name: String,
+) -> Result<Vec<BaselineVariance>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT field, value FROM financial_baselines
+         WHERE office_id = ?1 AND scenario_name = ?2 AND month = ?3
+         ORDER BY field"
+    )?;
+    
+    let baselines: Vec<(String, f64)> = stmt
+        .query_map(params![office_id, scenario_name.trim(), month], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    if baselines.is_empty() {
+        return Err(LabPulseError::NotFound(format!(
+            "No '{}' baseline set for office {} month {}",
+            scenario_name.trim(), office_id, month
+        )));
+    }
+    
+    let mut variances = Vec::with_capacity(baselines.len());
+    for (field, baseline) in baselines {
+        // Field names were validated against ADJUSTABLE_FINANCIAL_FIELDS on write
+        if !ADJUSTABLE_FINANCIAL_FIELDS.contains(&field.as_str()) {
+            continue;
+        }
+        
+        let actual: Option<f64> = match conn.query_row(
+            &format!(
+                "SELECT {} FROM monthly_financials WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+                field
+            ),
+            params![office_id, year, month],
+            |row| row.get(0),
+        ) {
+            Ok(value) => value,
+            Err(rusqlite::Error::QueryReturnedNoRows) => None,
+            Err(e) => return Err(e.into()),
+        };
+        
+        variances.push(BaselineVariance {
+            variance: actual.map(|a| a - baseline),
+            field,
+            baseline,
+            actual,
+        });
+    }
+    
+    Ok(variances)
+}
+
+// Delete an office's financial record for one month; returns whether a row was removed
+#[tauri::command]
+pub fn delete_financial_data(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<bool, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let deleted = conn.execute(
+        "DELETE FROM monthly_financials WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+    )?;
+    
+    Ok(deleted > 0)
+}
+
+// Delete an office's operations record for one month; returns whether a row was removed
+#[tauri::command]
+pub fn delete_operations_data(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<bool, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let deleted = conn.execute(
+        "DELETE FROM monthly_ops WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+    )?;
+    
+    Ok(deleted > 0)
+}
+
+// Delete an office's monthly volume record; weekly volume rows are left in place.
+// Returns whether a row was removed.
+#[tauri::command]
+pub fn delete_volume_data(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<bool, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let deleted = conn.execute(
+        "DELETE FROM monthly_volume WHERE office_id = ?1 AND year = ?2 AND month = ?3",
+        params![office_id, year, month],
+    )?;
+    
+    Ok(deleted > 0)
+}
+
+// Revenue dollars per lab-cost dollar; None when either is missing or lab expense is zero
+fn markup_ratio(revenue: Option<f64>, lab_exp: Option<f64>) -> Option<f64> {
+    match (revenue, lab_exp) {
+        (Some(rev), Some(lab)) if lab > 0.0 => Some(rev / lab),
+        _ => None,
+    }
+}
+
+// Get an office's blended markup (revenue / lab_exp_with_outside) for a month
+#[tauri::command]
+pub fn get_markup(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<Option<f64>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    Ok(load_single_office_month_metrics(&conn, office_id, year, month)?
+        .and_then(|m| markup_ratio(m.revenue, m.lab_exp)))
+}
+
+// One office's markup for the distribution
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OfficeMarkup {
+    pub office_id: i64,
+    pub office_name: String,
+    pub markup: f64,
+}
+
+// Company-wide spread of markups for a month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarkupDistribution {
+    pub offices: Vec<OfficeMarkup>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub median: Option<f64>,
+    pub average: Option<f64>,
+}
+
+// Get every office's markup for a month, lowest first, with summary statistics.
+// Offices without revenue or lab expense are left out.
+#[tauri::command]
+pub fn get_markup_distribution(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<MarkupDistribution, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut offices: Vec<OfficeMarkup> = load_office_month_metrics(&conn, year, month)?
+        .into_iter()
+        .filter_map(|m| {
+            markup_ratio(m.revenue, m.lab_exp).map(|markup| OfficeMarkup {
+                office_id: m.office_id,
+                office_name: m.office_name,
+                markup,
+            })
+        })
+        .collect();
+    
+    offices.sort_by(|a, b| a.markup.total_cmp(&b.markup));
+    
+    let n = offices.len();
+    let median = match n {
+        0 => None,
+        _ if n % 2 == 1 => Some(offices[n / 2].markup),
+        _ => Some((offices[n / 2 - 1].markup + offices[n / 2].markup) / 2.0),
+    };
+    let average = (n > 0).then(|| offices.iter().map(|o| o.markup).sum::<f64>() / n as f64);
+    
+    Ok(MarkupDistribution {
+        min: offices.first().map(|o| o.markup),
+        max: offices.last().map(|o| o.markup),
+        median,
+        average,
+        offices,
+    })
+}
+
+// Get an office's monthly financials over an inclusive range, oldest first
+#[tauri::command]
+pub fn get_financial_history(
+    db: State<DbConnection>,
+    office_id: i64,
+    start_year: i32,
+    start_month: i32,
+    end_year: i32,
+    end_month: i32,
+) -> Result<Vec<FinancialData>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month_range(start_year, start_month, end_year, end_month)?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT id, office_id, year, month, revenue, lab_exp_no_outside,
+                lab_exp_with_outside, outside_lab_spend, teeth_supplies,
+                lab_supplies, lab_hub, lss_expense, personnel_exp, overtime_exp, bonus_exp
+         FROM monthly_financials
+         WHERE office_id = ?1
+           AND (year * 100 + month) BETWEEN (?2 * 100 + ?3) AND (?4 * 100 + ?5)
+         ORDER BY year, month"
+    )?;
+    
+    let history = stmt.query_map(
+        params![office_id, start_year, start_month, end_year, end_month],
+        financial_data_from_row,
+    )?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(history)
+}
+
+// Every (year, month) in an inclusive range where the office has no financials,
+// oldest first, so DFOs can see which months an office skipped
+#[tauri::command]
+pub fn get_data_gaps(
+    db: State<DbConnection>,
+    office_id: i64,
+    start_year: i32,
+    start_month: i32,
+    end_year: i32,
+    end_month: i32,
+) -> Result<Vec<(i32, i32)>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month_range(start_year, start_month, end_year, end_month)?;
+    
+    conn.query_row(
+        "SELECT 1 FROM offices WHERE office_id = ?1",
+        params![office_id],
+        |_| Ok(()),
+    ).map_err(|e| match e {
+        rusqlite::Error::QueryReturnedNoRows => LabPulseError::NotFound(format!("Office {} not found", office_id)),
+        e => LabPulseError::from(e),
+    })?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT year, month FROM monthly_financials
+         WHERE office_id = ?1
+           AND (year * 100 + month) BETWEEN (?2 * 100 + ?3) AND (?4 * 100 + ?5)"
+    )?;
+    let reported = stmt.query_map(
+        params![office_id, start_year, start_month, end_year, end_month],
+        |row| Ok((row.get::<_, i32>(0)?, row.get::<_, i32>(1)?)),
+    )?
+    .collect::<Result<HashSet<_>, _>>()?;
+    
+    Ok(month_range(start_year, start_month, end_year, end_month)
+        .into_iter()
+        .filter(|period| !reported.contains(period))
+        .collect())
+}
+
+// How many offices reported each kind of data in one month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PeriodCoverage {
+    pub year: i32,
+    pub month: i32,
+    pub financial_offices: i64,
+    pub ops_offices: i64,
+    pub volume_offices: i64,
+}
+
+// Get every month in which any office has financials, ops, or volume, oldest first
+#[tauri::command]
+pub fn get_company_periods(db: State<DbConnection>) -> Result<Vec<PeriodCoverage>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "WITH periods AS (
+             SELECT year, month FROM monthly_financials
+             UNION SELECT year, month FROM monthly_ops
+             UNION SELECT year, month FROM monthly_volume
+         )
+         SELECT p.year, p.month,
+                (SELECT COUNT(DISTINCT office_id) FROM monthly_financials
+                 WHERE year = p.year AND month = p.month),
+                (SELECT COUNT(DISTINCT office_id) FROM monthly_ops
+                 WHERE year = p.year AND month = p.month),
+                (SELECT COUNT(DISTINCT office_id) FROM monthly_volume
+                 WHERE year = p.year AND month = p.month)
+         FROM periods p
+         ORDER BY p.year, p.month"
+    )?;
+    
+    let periods = stmt.query_map([], |row| {
+        Ok(PeriodCoverage {
+            year: row.get(0)?,
+            month: row.get(1)?,
+            financial_offices: row.get(2)?,
+            ops_offices: row.get(3)?,
+            volume_offices: row.get(4)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(periods)
+}
+
+// A month's financials next to the same month a year earlier
+#[derive(Debug, Serialize, Deserialize)]
+pub struct YoYComparison {
+    pub current: Option<FinancialData>,
+    pub prior_year: Option<FinancialData>,
+    pub revenue_change_percent: Option<f64>,
+    pub lab_exp_change_percent: Option<f64>,
+    pub personnel_change_percent: Option<f64>,
+}
+
+// Compare an office's month with the same month one year prior. Deltas are None
+// when either month is missing or the prior value is zero.
+#[tauri::command]
+pub fn get_year_over_year(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<YoYComparison, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let current = fetch_financial_data(&conn, office_id, year, month)?;
+    let prior_year = fetch_financial_data(&conn, office_id, year - 1, month)?;
+    
+    let change = |field: fn(&FinancialData) -> f64| match (&current, &prior_year) {
+        (Some(cur), Some(prev)) if field(prev) != 0.0 => {
+            Some((field(cur) - field(prev)) / field(prev) * 100.0)
+        }
+        _ => None,
+    };
+    
+    Ok(YoYComparison {
+        revenue_change_percent: change(|f| f.revenue),
+        lab_exp_change_percent: change(|f| f.lab_exp_with_outside),
+        personnel_change_percent: change(|f| f.personnel_exp),
+        current,
+        prior_year,
+    })
+}
+
+// Get a unit tier's monthly counts for a year (index 0 = January); None for
+// months without volume data
+#[tauri::command]
+pub fn get_tier_trend(
+    db: State<DbConnection>,
+    office_id: i64,
+    tier: String,
+    year: i32,
+) -> Result<Vec<Option<i32>>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let column = UNIT_TIERS
+        .iter()
+        .find(|(name, _)| *name == tier)
+        .map(|(_, col)| *col)
+        .ok_or_else(|| LabPulseError::Validation(format!("Unknown unit tier: {}", tier)))?;
+    
+    let mut stmt = conn.prepare(&format!(
+        "SELECT month, {} FROM monthly_volume WHERE office_id = ?1 AND year = ?2",
+        column
+    ))?;
+    
+    let mut trend = vec![None; 12];
+    let rows = stmt.query_map(params![office_id, year], |row| {
+        Ok((row.get::<_, i32>(0)?, row.get::<_, i32>(1)?))
+    })?;
+    
+    for row in rows {
+        let (month, units) = row?;
+        trend[(month - 1) as usize] = Some(units);
+    }
+    
+    Ok(trend)
+}
+
+// Get an office's year-to-date financial totals through the given month
+#[tauri::command]
+pub fn get_ytd_financials(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    through_month: i32,
+) -> Result<YtdTotals, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(through_month)?;
+    
+    Ok(ytd_totals(&conn, office_id, year, through_month)?)
+}
+
+// Get overtime dollars per backlog case for a month; None when backlog is zero
+// or either the financials or ops record is missing
+#[tauri::command]
+pub fn get_overtime_per_backlog(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<Option<f64>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let metrics = load_single_office_month_metrics(&conn, office_id, year, month)?;
+    
+    Ok(metrics.and_then(|m| match (m.overtime_exp, m.backlog_count) {
+        (Some(overtime), Some(backlog)) if backlog > 0 => Some(overtime / backlog as f64),
+        _ => None,
+    }))
+}
+
+// Trailing-twelve-month totals ending at a month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TtmTotals {
+    pub start_year: i32,
+    pub start_month: i32,
+    pub end_year: i32,
+    pub end_month: i32,
+    #[serde(flatten)]
+    pub totals: FinancialTotals,
+}
+
+// Get an office's financial totals for the 12 months ending at the given month
+#[tauri::command]
+pub fn get_ttm_financials(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<TtmTotals, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    // Twelve months back from the end month, inclusive: Mar 2024 starts at Apr 2023
+    let (start_year, start_month) = if month == 12 { (year, 1) } else { (year - 1, month + 1) };
+    
+    Ok(TtmTotals {
+        start_year,
+        start_month,
+        end_year: year,
+        end_month: month,
+        totals: sum_financials(&conn, Some(office_id), start_year, start_month, year, month)?,
+    })
+}
+
+// A data row dated after the current month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FuturePeriod {
+    pub table_name: String,
+    pub record_id: i64,
+    pub office_id: i64,
+    pub year: i32,
+    pub month: i32,
+}
+
+// Find financial, ops, and volume rows whose period is after the current month
+#[tauri::command]
+pub fn find_future_periods(db: State<DbConnection>) -> Result<Vec<FuturePeriod>, LabPulseError> {
+    use chrono::Datelike;
+    
+    let conn = db.0.get()?;
+    
+    let today = chrono::Local::now().date_naive();
+    let current_period = today.year() * 100 + today.month() as i32;
+    
+    let mut future = Vec::new();
+    for table in ["monthly_financials", "monthly_ops", "monthly_volume"] {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, office_id, year, month FROM {}
+             WHERE (year * 100 + month) > ?1
+             ORDER BY year, month, office_id",
+            table
+        ))?;
+        
+        let rows = stmt.query_map(params![current_period], |row| {
+            Ok(FuturePeriod {
+                table_name: table.to_string(),
+                record_id: row.get(0)?,
+                office_id: row.get(1)?,
+                year: row.get(2)?,
+                month: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+        
+        future.extend(rows);
+    }
+    
+    Ok(future)
+}
+
+// A peer office and its distance from the target's expense profile
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimilarOffice {
+    pub office_id: i64,
+    pub office_name: String,
+    pub model: String,
+    pub distance: f64,
+    pub lab_exp_percent: f64,
+    pub personnel_percent: f64,
+    pub overtime_percent: f64,
+    pub supplies_percent: f64,
+}
+
+// Find the offices whose expense ratios (lab, personnel, overtime, supplies as %
+// of revenue) are closest to the target's for a month. Each ratio is z-scored
+// across all offices with revenue that month so no single ratio dominates, and
+// offices are ranked by Euclidean distance.
+#[tauri::command]
+pub fn find_similar_offices(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+    limit: i64,
+) -> Result<Vec<SimilarOffice>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT o.office_id, o.office_name, o.model,
+                COALESCE(mf.lab_exp_with_outside, 0) / mf.revenue * 100.0,
+                COALESCE(mf.personnel_exp, 0) / mf.revenue * 100.0,
+                COALESCE(mf.overtime_exp, 0) / mf.revenue * 100.0,
+                (COALESCE(mf.teeth_supplies, 0) + COALESCE(mf.lab_supplies, 0)) / mf.revenue * 100.0
+         FROM offices o
+         JOIN monthly_financials mf ON mf.office_id = o.office_id
+         WHERE mf.year = ?1 AND mf.month = ?2 AND mf.revenue > 0"
+    )?;
+    
+    let profiles: Vec<(i64, String, String, [f64; 4])> = stmt.query_map(params![year, month], |row| {
+        Ok((
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            [row.get(3)?, row.get(4)?, row.get(5)?, row.get(6)?],
+        ))
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    let target = profiles
+        .iter()
+        .find(|(id, _, _, _)| *id == office_id)
+        .map(|(_, _, _, ratios)| *ratios)
+        .ok_or_else(|| LabPulseError::NotFound(format!("Office {} has no revenue for {}-{:02}", office_id, year, month)))?;
+    
+    // Per-dimension standard deviation; a dimension with no spread is ignored
+    let n = profiles.len() as f64;
+    let spreads: Vec<f64> = (0..4)
+        .map(|dim| {
+            let mean = profiles.iter().map(|p| p.3[dim]).sum::<f64>() / n;
+            let variance = profiles.iter().map(|p| (p.3[dim] - mean).powi(2)).sum::<f64>() / n;
+            variance.sqrt()
+        })
+        .collect();
+    
+    let mut similar: Vec<SimilarOffice> = profiles
+        .into_iter()
+        .filter(|(id, _, _, _)| *id != office_id)
+        .map(|(id, name, model, ratios)| {
+            let distance = (0..4)
+                .filter(|&dim| spreads[dim] > 0.0)
+                .map(|dim| ((ratios[dim] - target[dim]) / spreads[dim]).powi(2))
+                .sum::<f64>()
+                .sqrt();
+            SimilarOffice {
+                office_id: id,
+                office_name: name,
+                model,
+                distance,
+                lab_exp_percent: ratios[0],
+                personnel_percent: ratios[1],
+                overtime_percent: ratios[2],
+                supplies_percent: ratios[3],
+            }
+        })
+        .collect();
+    
+    similar.sort_by(|a, b| a.distance.total_cmp(&b.distance).then(a.office_id.cmp(&b.office_id)));
+    similar.truncate(limit.max(0) as usize);
+    
+    Ok(similar)
+}
+
+// Company-wide metric weighted by office revenue, alongside the plain average
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WeightedMetric {
+    pub metric: String,
+    pub year: i32,
+    pub month: i32,
+    pub weighted_average: Option<f64>,
+    pub unweighted_average: Option<f64>,
+    pub offices_included: usize,
+}
+
+// Average a metric across offices weighting each by its share of revenue, so large
+// offices count for more. Offices without revenue or without the metric are skipped.
+#[tauri::command]
+pub fn get_revenue_weighted_metric(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+    metric: String,
+) -> Result<WeightedMetric, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_metric(&metric)?;
+    validate_month(month)?;
+    
+    let pairs: Vec<(f64, f64)> = load_office_month_metrics(&conn, year, month)?
+        .iter()
+        .filter_map(|office| match (office.value(&metric), office.revenue) {
+            (Some(value), Some(revenue)) if revenue > 0.0 => Some((value, revenue)),
+            _ => None,
+        })
+        .collect();
+    
+    let total_revenue: f64 = pairs.iter().map(|(_, revenue)| revenue).sum();
+    let (weighted_average, unweighted_average) = if pairs.is_empty() {
+        (None, None)
+    } else {
+        (
+            Some(pairs.iter().map(|(value, revenue)| value * revenue).sum::<f64>() / total_revenue),
+            Some(pairs.iter().map(|(value, _)| value).sum::<f64>() / pairs.len() as f64),
+        )
+    };
+    
+    Ok(WeightedMetric {
+        metric,
+        year,
+        month,
+        weighted_average,
+        unweighted_average,
+        offices_included: pairs.len(),
+    })
+}
+
+// Offices missing a managing dentist, a phone number, or any contacts, so the
+// directory can be completed before outreach
+#[tauri::command]
+pub fn find_incomplete_office_profiles(db: State<DbConnection>) -> Result<Vec<Office>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT o.office_id, o.office_name, o.model, o.address, o.phone, o.managing_dentist, o.dfo, o.standardization_status
+         FROM offices o
+         WHERE TRIM(COALESCE(o.managing_dentist, '')) = ''
+            OR TRIM(COALESCE(o.phone, '')) = ''
+            OR NOT EXISTS (SELECT 1 FROM office_contacts oc WHERE oc.office_id = o.office_id)
+         ORDER BY o.office_name"
+    )?;
+    
+    let offices = stmt.query_map([], |row| {
+        Ok(Office {
+            office_id: row.get(0)?,
+            office_name: row.get(1)?,
+            model: row.get(2)?,
+            address: row.get(3)?,
+            phone: row.get(4)?,
+            managing_dentist: row.get(5)?,
+            dfo: row.get(6)?,
+            standardization_status: row.get(7)?,
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(offices)
+}
+
+// One unit tier's volume this month against the month before
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TierGrowth {
+    pub tier: String,
+    pub units: i32,
+    pub previous_units: Option<i32>,
+    pub growth_percent: Option<f64>,
+}
+
+// Month-over-month unit growth per tier for an office. Growth is None when the
+// previous month has no volume row or the tier had no units then.
+#[tauri::command]
+pub fn get_tier_growth(
+    db: State<DbConnection>,
+    office_id: i64,
+    year: i32,
+    month: i32,
+) -> Result<Vec<TierGrowth>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let current = load_tier_units(&conn, office_id, year, month)?.ok_or_else(|| {
+        LabPulseError::NotFound(format!("No volume data for office {} in {}/{}", office_id, month, year))
+    })?;
+    let (prev_year, prev_month) = previous_month(year, month);
+    let previous = load_tier_units(&conn, office_id, prev_year, prev_month)?;
+    
+    Ok(UNIT_TIERS
+        .iter()
+        .enumerate()
+        .map(|(i, (tier, _))| {
+            let units = current[i];
+            let previous_units = previous.as_ref().map(|counts| counts[i]);
+            TierGrowth {
+                tier: tier.to_string(),
+                units,
+                previous_units,
+                growth_percent: previous_units
+                    .filter(|prev| *prev > 0)
+                    .map(|prev| (units - prev) as f64 / prev as f64 * 100.0),
+            }
+        })
+        .collect())
+}
+
+// Consolidated financials across every office for one month. Ratios come from
+// the summed dollars, so lab_exp_percent is weighted by each office's revenue.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompanySummary {
+    pub year: i32,
+    pub month: i32,
+    pub offices_reporting: i64,
+    pub totals: FinancialTotals,
+}
+
+// Top-line company totals for the month
+#[tauri::command]
+pub fn get_company_summary(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<CompanySummary, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let totals = sum_financials(&conn, None, year, month, year, month)?;
+    
+    Ok(CompanySummary {
+        year,
+        month,
+        offices_reporting: totals.months_reported as i64,
+        totals,
+    })
+}
+
+// Company-wide bottom line for one month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompanyMargin {
+    pub year: i32,
+    pub month: i32,
+    pub revenue: f64,
+    pub total_expenses: f64,
+    pub margin_dollars: f64,
+    pub margin_percent: Option<f64>,
+    pub offices_reporting: i64,
+}
+
+// Sum revenue and total expenses across every office with financials for the month
+#[tauri::command]
+pub fn get_company_margin_dollars(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<CompanyMargin, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let totals = sum_financials(&conn, None, year, month, year, month)?;
+    let margin_dollars = totals.revenue - totals.total_expenses;
+    
+    Ok(CompanyMargin {
+        year,
+        month,
+        revenue: totals.revenue,
+        total_expenses: totals.total_expenses,
+        margin_dollars,
+        margin_percent: totals.margin_percent,
+        // One financials row per office and month, so rows are offices
+        offices_reporting: totals.months_reported as i64,
+    })
+}
+
+// Office running at a loss for the month
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnprofitableOffice {
+    pub office_id: i64,
+    pub office_name: String,
+    pub revenue: f64,
+    pub total_expenses: f64,
+    pub margin_dollars: f64,
+    pub margin_percent: Option<f64>,
+}
+
+// Offices whose revenue minus lab, supplies and labor expenses is negative for
+// the month, most negative first, for escalation on the monthly call
+#[tauri::command]
+pub fn find_unprofitable_offices(
+    db: State<DbConnection>,
+    year: i32,
+    month: i32,
+) -> Result<Vec<UnprofitableOffice>, LabPulseError> {
+    let conn = db.0.get()?;
+    
+    validate_month(month)?;
+    
+    let mut stmt = conn.prepare(&format!(
+        "SELECT office_id, office_name, revenue, total_expenses
+         FROM (
+             SELECT o.office_id, o.office_name,
+                    COALESCE(mf.revenue, 0) AS revenue, {} AS total_expenses
+             FROM monthly_financials mf
+             JOIN offices o ON o.office_id = mf.office_id
+             WHERE mf.year = ?1 AND mf.month = ?2
+         )
+         WHERE revenue - total_expenses < 0
+         ORDER BY revenue - total_expenses, office_id",
+        total_expense_sql("mf.")
+    ))?;
+    
+    let offices = stmt.query_map(params![year, month], |row| {
+        let revenue: f64 = row.get(2)?;
+        let total_expenses: f64 = row.get(3)?;
+        let margin_dollars = revenue - total_expenses;
+        Ok(UnprofitableOffice {
+            office_id: row.get(0)?,
+            office_name: row.get(1)?,
+            revenue,
+            total_expenses,
+            margin_dollars,
+            margin_percent: (revenue > 0.0).then(|| margin_dollars / revenue * 100.0),
+        })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(offices)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,1384 @@
+use super::*;
+use r2d2_sqlite::SqliteConnectionManager;
+use tauri::test::{mock_app, MockRuntime};
+use tauri::{App, Manager};
+
+// Mock app managing a migrated in-memory database. The pool holds a single
+// connection because every :memory: connection is a separate database.
+fn test_app() -> App<MockRuntime> {
+    let manager = SqliteConnectionManager::memory();
+    let pool = r2d2::Pool::builder().max_size(1).build(manager).unwrap();
+    run_migrations(&pool.get().unwrap()).unwrap();
+    let app = mock_app();
+    app.manage(DbConnection(pool));
+    app
+}
+
+fn seed(app: &App<MockRuntime>, sql: &str) {
+    app.state::<DbConnection>().0.get().unwrap().execute_batch(sql).unwrap();
+}
+
+// Write rows (header first) to one sheet of a temp .xlsx; numeric-looking cells become numbers
+fn xlsx_file(name: &str, sheet_name: &str, rows: &[&[&str]]) -> String {
+    let mut workbook = rust_xlsxwriter::Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name(sheet_name).unwrap();
+    for (r, row) in rows.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            match cell.parse::<f64>() {
+                Ok(n) => sheet.write_number(r as u32, c as u16, n).unwrap(),
+                Err(_) => sheet.write_string(r as u32, c as u16, *cell).unwrap(),
+            };
+        }
+    }
+    let path = std::env::temp_dir().join(name);
+    workbook.save(&path).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+fn scalar<T: rusqlite::types::FromSql>(app: &App<MockRuntime>, sql: &str) -> T {
+    app.state::<DbConnection>().0.get().unwrap().query_row(sql, [], |row| row.get(0)).unwrap()
+}
+
+#[test]
+fn tier_revenue_estimate_multiplies_priced_tiers() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_volume (office_id, year, month, economy_units, premium_units, ultimate_units)
+            VALUES (1, 2024, 3, 10, 4, 2);
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES (1, 2024, 3, 5000);
+    ");
+    let prices = HashMap::from([("economy".to_string(), 100.0), ("premium".to_string(), 250.0)]);
+    
+    let estimate = get_tier_revenue_estimate(app.state(), 1, 2024, 3, prices).unwrap();
+    
+    assert_eq!(estimate.tiers.len(), 2);
+    assert_eq!(estimate.tiers[0].tier, "economy");
+    assert_eq!(estimate.tiers[0].estimated_revenue, 1000.0);
+    assert_eq!(estimate.tiers[1].tier, "premium");
+    assert_eq!(estimate.tiers[1].estimated_revenue, 1000.0);
+    assert_eq!(estimate.total_estimate, 2000.0);
+    assert_eq!(estimate.actual_revenue, Some(5000.0));
+}
+
+#[test]
+fn tier_revenue_estimate_rejects_unknown_tier() {
+    let app = test_app();
+    seed(&app, "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');");
+    let prices = HashMap::from([("platinum".to_string(), 100.0)]);
+    
+    let err = get_tier_revenue_estimate(app.state(), 1, 2024, 3, prices).unwrap_err();
+    assert!(matches!(err, LabPulseError::Validation(_)));
+}
+
+#[test]
+fn clear_office_month_removes_period_but_keeps_office() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO staff (office_id, name, job_title) VALUES (1, 'Ann', 'Technician');
+        INSERT INTO office_contacts (office_id, role, name) VALUES (1, 'Lab Manager', 'Bo');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES (1, 2024, 3, 100), (1, 2024, 4, 200);
+        INSERT INTO monthly_ops (office_id, year, month, backlog_case_count) VALUES (1, 2024, 3, 5);
+        INSERT INTO monthly_volume (office_id, year, month) VALUES (1, 2024, 3);
+        INSERT INTO notes_actions (office_id, year, month, note_text) VALUES (1, 2024, 3, 'redo');
+    ");
+    
+    let summary = clear_office_month(app.state(), 1, 2024, 3).unwrap();
+    
+    assert_eq!(summary.financials_removed, 1);
+    assert_eq!(summary.operations_removed, 1);
+    assert_eq!(summary.volume_removed, 1);
+    assert_eq!(summary.notes_removed, 1);
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM monthly_financials WHERE month = 3"), 0);
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM monthly_financials WHERE month = 4"), 1);
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM offices"), 1);
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM staff"), 1);
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM office_contacts"), 1);
+}
+
+#[test]
+fn clear_office_month_rejects_invalid_month() {
+    let app = test_app();
+    let err = clear_office_month(app.state(), 1, 2024, 13).unwrap_err();
+    assert!(matches!(err, LabPulseError::Validation(_)));
+}
+
+#[test]
+fn pearson_correlation_of_linear_series_is_one() {
+    let pairs: Vec<(f64, f64)> = (1..=6).map(|x| (x as f64, 3.0 * x as f64 + 2.0)).collect();
+    let r = pearson_correlation(&pairs).unwrap();
+    assert!((r - 1.0).abs() < 1e-9);
+    
+    let inverse: Vec<(f64, f64)> = (1..=6).map(|x| (x as f64, -2.0 * x as f64)).collect();
+    assert!((pearson_correlation(&inverse).unwrap() + 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn pearson_correlation_needs_enough_points() {
+    assert_eq!(pearson_correlation(&[]), None);
+    assert_eq!(pearson_correlation(&[(1.0, 2.0)]), None);
+}
+
+#[test]
+fn overtime_backlog_correlation_pairs_with_next_month_backlog() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, overtime_exp) VALUES
+            (1, 2023, 11, 100), (1, 2023, 12, 200), (1, 2024, 1, 300), (1, 2024, 2, 400);
+        INSERT INTO monthly_ops (office_id, year, month, backlog_case_count) VALUES
+            (1, 2023, 12, 10), (1, 2024, 1, 20), (1, 2024, 2, 30), (1, 2024, 3, 40);
+    ");
+    
+    let r = get_overtime_backlog_correlation(app.state(), 1).unwrap().unwrap();
+    assert!((r - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn repair_volume_totals_fixes_only_wrong_rows() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_volume (office_id, year, month, lab_setups, lab_finishes, backlog_in_lab,
+                                    economy_units, premium_units, total_weekly_units)
+            VALUES (1, 2024, 1, 2, 3, 5, 4, 6, 10),
+                   (1, 2024, 2, 2, 3, 5, 4, 6, 99);
+    ");
+    
+    let mismatches = find_volume_total_mismatches(app.state()).unwrap();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].month, 2);
+    assert_eq!(mismatches[0].stored_total_weekly_units, 99);
+    assert_eq!(mismatches[0].computed_total_weekly_units, 10);
+    
+    assert_eq!(repair_volume_totals(app.state()).unwrap(), 1);
+    assert_eq!(scalar::<i32>(&app, "SELECT total_weekly_units FROM monthly_volume WHERE month = 2"), 10);
+    assert!(find_volume_total_mismatches(app.state()).unwrap().is_empty());
+    assert_eq!(repair_volume_totals(app.state()).unwrap(), 0);
+}
+
+#[test]
+fn dfo_list_is_sorted_and_flags_unassigned() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model, dfo) VALUES
+            (1, 'North', 'PO', 'Zed'), (2, 'South', 'PO', 'Amy'), (3, 'East', 'PLLC', 'Zed'),
+            (4, 'West', 'PO', NULL);
+    ");
+    
+    let list = get_dfo_list(app.state()).unwrap();
+    assert_eq!(list.dfos, vec!["Amy".to_string(), "Zed".to_string()]);
+    assert!(list.has_unassigned);
+    
+    seed(&app, "UPDATE offices SET dfo = 'Amy' WHERE office_id = 4");
+    assert!(!get_dfo_list(app.state()).unwrap().has_unassigned);
+}
+
+#[test]
+fn alerts_csv_round_trips_through_a_csv_parser() {
+    let app = test_app();
+    seed(&app, r#"
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North, Main St', 'PO');
+        INSERT INTO alerts (office_id, year, month, alert_type, severity, message, is_dismissed) VALUES
+            (1, 2024, 3, 'lab_ratio', 'critical', 'Ratio "high", check
+supplies', 0),
+            (1, 2024, 3, 'overtime', 'warning', 'Dismissed one', 1);
+    "#);
+    let path = std::env::temp_dir().join("labpulse_alerts_round_trip.csv");
+    let output_path = path.to_string_lossy().to_string();
+    
+    export_alerts_csv(app.state(), 2024, 3, false, output_path.clone()).unwrap();
+    let mut reader = csv::Reader::from_path(&path).unwrap();
+    let records: Vec<csv::StringRecord> = reader.records().collect::<Result<_, _>>().unwrap();
+    
+    assert_eq!(
+        reader.headers().unwrap(),
+        vec!["office_id", "office_name", "alert_type", "severity", "message", "is_dismissed", "created_at"]
+    );
+    assert_eq!(records.len(), 1);
+    assert_eq!(&records[0][1], "North, Main St");
+    assert_eq!(&records[0][4], "Ratio \"high\", check\nsupplies");
+    assert_eq!(&records[0][5], "0");
+    
+    export_alerts_csv(app.state(), 2024, 3, true, output_path).unwrap();
+    assert_eq!(csv::Reader::from_path(&path).unwrap().records().count(), 2);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn competition_ranks_share_ties_and_skip() {
+    assert_eq!(competition_ranks(&[30.0, 20.0, 20.0, 10.0]), vec![1, 2, 2, 4]);
+    assert!(competition_ranks(&[]).is_empty());
+}
+
+#[test]
+fn rank_by_margin_ties_share_a_rank() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES
+            (1, 'A', 'PO'), (2, 'B', 'PO'), (3, 'C', 'PLLC'), (4, 'D', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, personnel_exp) VALUES
+            (1, 2024, 5, 1000, 800), (2, 2024, 5, 2000, 1600), (3, 2024, 5, 1000, 900);
+    ");
+    
+    let ranks = rank_by_margin(app.state(), 2024, 5).unwrap();
+    let summary: Vec<(i64, Option<i32>)> = ranks.iter().map(|r| (r.office_id, r.rank)).collect();
+    
+    assert_eq!(summary, vec![(1, Some(1)), (2, Some(1)), (3, Some(3)), (4, None)]);
+    assert!((ranks[2].margin_percent.unwrap() - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn financial_adjustment_adds_delta_to_existing_value() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_no_outside, lab_exp_with_outside)
+            VALUES (1, 2024, 3, 10000, 100, 150);
+    ");
+    let path = xlsx_file("labpulse_adjustments.xlsx", "Sheet1", &[
+        &["office_id", "year", "month", "field", "delta"],
+        &["1", "2024", "3", "revenue", "500"],
+        &["1", "2024", "3", "lab_exp_with_outside", "25"],
+        &["1", "2024", "4", "revenue", "500"],
+    ]);
+    
+    let summary = apply_financial_adjustments(app.state(), path).unwrap();
+    
+    assert_eq!(summary.rows_updated, 2);
+    assert_eq!(summary.warnings.len(), 1);
+    assert!(summary.warnings[0].contains("No financial record"));
+    assert_eq!(scalar::<f64>(&app, "SELECT revenue FROM monthly_financials WHERE month = 3"), 10500.0);
+    assert_eq!(scalar::<f64>(&app, "SELECT outside_lab_spend FROM monthly_financials WHERE month = 3"), 75.0);
+}
+
+#[test]
+fn lab_vs_clinic_split_for_office_and_company() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO');
+        INSERT INTO monthly_volume (office_id, year, month, backlog_in_lab, backlog_in_clinic) VALUES
+            (1, 2024, 6, 30, 10), (2, 2024, 6, 10, 50), (1, 2024, 7, 0, 0);
+    ");
+    
+    let split = get_lab_vs_clinic_split(app.state(), 1, 2024, 6).unwrap().unwrap();
+    assert_eq!((split.backlog_in_lab, split.backlog_in_clinic), (30, 10));
+    assert_eq!(split.lab_percent, 75.0);
+    assert_eq!(split.clinic_percent, 25.0);
+    
+    let company = get_company_lab_vs_clinic_split(app.state(), 2024, 6).unwrap().unwrap();
+    assert_eq!((company.backlog_in_lab, company.backlog_in_clinic), (40, 60));
+    assert_eq!(company.lab_percent, 40.0);
+    
+    assert!(get_lab_vs_clinic_split(app.state(), 1, 2024, 7).unwrap().is_none());
+    assert!(get_lab_vs_clinic_split(app.state(), 2, 2024, 7).unwrap().is_none());
+}
+
+#[test]
+fn dashboard_custom_returns_only_requested_metrics() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PLLC');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside)
+            VALUES (1, 2024, 2, 2000, 500);
+    ");
+    let metrics = vec!["revenue".to_string(), "lab_exp_percent".to_string()];
+    
+    let rows = get_dashboard_custom(app.state(), 2024, 2, metrics).unwrap();
+    
+    assert_eq!(rows.len(), 2);
+    for row in &rows {
+        let mut keys: Vec<&String> = row.metrics.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["lab_exp_percent", "revenue"]);
+    }
+    assert_eq!(rows[0].metrics["revenue"], Some(2000.0));
+    assert_eq!(rows[0].metrics["lab_exp_percent"], Some(25.0));
+    assert_eq!(rows[1].metrics["revenue"], None);
+    
+    let err = get_dashboard_custom(app.state(), 2024, 2, vec!["profit".to_string()]).unwrap_err();
+    assert!(matches!(err, LabPulseError::Validation(_)));
+}
+
+#[test]
+fn supply_reconciliation_flags_supplies_above_lab_expense() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, teeth_supplies, lab_supplies, lab_hub, lss_expense, lab_exp_no_outside)
+            VALUES (1, 2024, 1, 100, 50, 25, 25, 400),
+                   (1, 2024, 2, 300, 100, 50, 50, 400);
+    ");
+    
+    let ok = check_supply_reconciliation(app.state(), 1, 2024, 1).unwrap();
+    assert_eq!(ok.supplies_total, 200.0);
+    assert_eq!(ok.difference, 200.0);
+    assert!(!ok.supplies_exceed_lab_expense);
+    
+    let over = check_supply_reconciliation(app.state(), 1, 2024, 2).unwrap();
+    assert_eq!(over.supplies_total, 500.0);
+    assert_eq!(over.difference, -100.0);
+    assert!(over.supplies_exceed_lab_expense);
+    
+    let missing = check_supply_reconciliation(app.state(), 1, 2024, 3).unwrap_err();
+    assert!(matches!(missing, LabPulseError::NotFound(_)));
+}
+
+#[test]
+fn latest_notes_keep_only_newest_per_office() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO');
+        INSERT INTO notes_actions (office_id, year, month, note_text) VALUES
+            (1, 2023, 12, 'north old'), (1, 2024, 2, 'north new'),
+            (2, 2024, 1, 'south new'), (2, 2023, 11, 'south old');
+    ");
+    
+    let notes = get_latest_notes(app.state(), 10).unwrap();
+    let texts: Vec<&str> = notes.iter().map(|n| n.note_text.as_str()).collect();
+    assert_eq!(texts, vec!["north new", "south new"]);
+    assert_eq!(notes[1].office_name, "South");
+    
+    assert_eq!(get_latest_notes(app.state(), 1).unwrap().len(), 1);
+}
+
+#[test]
+fn staffing_trend_series_places_values_by_month() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_ops (office_id, year, month, staffing_trend) VALUES
+            (1, 2024, 1, 0.5), (1, 2024, 4, -1.0), (1, 2024, 12, 2.0), (1, 2023, 6, 9.0);
+    ");
+    
+    let series = get_staffing_trend_series(app.state(), 1, 2024).unwrap();
+    
+    assert_eq!(series.len(), 12);
+    assert_eq!(series[0], Some(0.5));
+    assert_eq!(series[3], Some(-1.0));
+    assert_eq!(series[11], Some(2.0));
+    assert_eq!(series[5], None);
+    assert_eq!(series.iter().filter(|v| v.is_some()).count(), 3);
+}
+
+#[test]
+fn overtime_vs_model_flags_office_over_allowance() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, overtime_exp) VALUES (1, 2024, 4, 10000, 500);
+        INSERT INTO monthly_ops (office_id, year, month, labor_model_value) VALUES (1, 2024, 4, 30);
+    ");
+    
+    // Default 10% allowance of a 30% labor model: 3% expected against 5% actual
+    let gap = get_overtime_vs_model(app.state(), 1, 2024, 4).unwrap();
+    assert_eq!(gap.actual_overtime_percent, Some(5.0));
+    assert_eq!(gap.expected_overtime_percent, Some(3.0));
+    assert_eq!(gap.gap_points, Some(2.0));
+    assert!(gap.exceeds_allowance);
+    
+    seed(&app, "INSERT INTO settings (key, value) VALUES ('overtime_allowance_percent', '20')");
+    let gap = get_overtime_vs_model(app.state(), 1, 2024, 4).unwrap();
+    assert_eq!(gap.gap_points, Some(-1.0));
+    assert!(!gap.exceeds_allowance);
+}
+
+#[test]
+fn bulk_financials_aborts_when_most_offices_are_unknown() {
+    let app = test_app();
+    seed(&app, "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');");
+    let header: &[&str] = &["office_id", "year", "month", "revenue"];
+    let path = xlsx_file("labpulse_unknown_offices.xlsx", "monthly_financials", &[
+        header,
+        &["1", "2024", "1", "1000"],
+        &["7", "2024", "1", "1000"],
+        &["8", "2024", "1", "1000"],
+        &["9", "2024", "1", "1000"],
+    ]);
+    
+    let err = import_bulk_financials(app.state(), path).unwrap_err();
+    
+    assert!(matches!(err, LabPulseError::Import(_)));
+    assert!(err.to_string().contains("3 of 4 office IDs"));
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM monthly_financials"), 0);
+    
+    // At the 50% default threshold the import goes ahead
+    let path = xlsx_file("labpulse_half_unknown_offices.xlsx", "monthly_financials", &[
+        header,
+        &["1", "2024", "1", "1000"],
+        &["7", "2024", "1", "1000"],
+    ]);
+    let summary = import_bulk_financials(app.state(), path).unwrap();
+    assert_eq!(summary.rows_inserted, 1);
+}
+
+#[test]
+fn average_tenure_mixes_date_formats_and_skips_future_hires() {
+    let app = test_app();
+    let today = chrono::Local::now().date_naive();
+    let ten_months_ago = today - chrono::Duration::days(304);
+    let thirty_months_ago = today - chrono::Duration::days(913);
+    let next_month = today + chrono::Duration::days(30);
+    seed(&app, &format!(
+        "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+         INSERT INTO staff (office_id, name, job_title, hire_date) VALUES
+             (1, 'Ann', 'Tech', '{}'),
+             (1, 'Bo', 'Tech', '{}'),
+             (1, 'Cy', 'Tech', '{}'),
+             (1, 'Di', 'Tech', 'unknown'),
+             (1, 'Ed', 'Tech', NULL);",
+        ten_months_ago.format("%Y-%m-%d"),
+        thirty_months_ago.format("%m/%d/%Y"),
+        next_month.format("%Y-%m-%d"),
+    ));
+    
+    let average = get_average_tenure(app.state(), 1).unwrap().unwrap();
+    let expected = (304.0 + 913.0) / 2.0 / 30.4375;
+    assert!((average - expected).abs() < 0.1, "average {} vs {}", average, expected);
+    
+    assert_eq!(get_average_tenure(app.state(), 2).unwrap(), None);
+}
+
+#[test]
+fn office_report_html_contains_name_and_revenue() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model, dfo) VALUES (1, 'North & Main', 'PO', 'Amy');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside)
+            VALUES (1, 2024, 3, 12345.5, 2469.1);
+    ");
+    let path = std::env::temp_dir().join("labpulse_office_report.html");
+    
+    export_office_report_html(app.state(), 1, 2024, path.to_string_lossy().to_string()).unwrap();
+    let html = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    
+    assert!(html.contains("<h1>North &amp; Main</h1>"));
+    assert!(html.contains("<td>March</td><td>$12345.50</td>"));
+    assert!(html.contains("<td>20.0%</td>"));
+}
+
+#[test]
+fn quarterly_volume_sums_the_three_months() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_volume (office_id, year, month, economy_units, premium_units) VALUES
+            (1, 2024, 3, 100, 100), (1, 2024, 4, 10, 1), (1, 2024, 5, 20, 1), (1, 2024, 6, 30, 1),
+            (1, 2024, 7, 100, 100);
+        INSERT INTO weekly_volume (office_id, year, week_number) VALUES
+            (1, 2024, 13), (1, 2024, 14), (1, 2024, 20), (1, 2024, 26), (1, 2024, 27);
+    ");
+    
+    let q2 = get_quarterly_volume(app.state(), 1, 2024, 2).unwrap();
+    
+    let units = |tier: &str| q2.tiers.iter().find(|t| t.tier == tier).unwrap().units;
+    assert_eq!(units("economy"), 60);
+    assert_eq!(units("premium"), 3);
+    assert_eq!(units("ultimate"), 0);
+    assert_eq!(q2.total_units, 63);
+    assert_eq!(q2.months_included, 3);
+    // ISO weeks 14-26 have their Thursday in April-June 2024
+    assert_eq!(q2.weeks_included, 3);
+    
+    let err = get_quarterly_volume(app.state(), 1, 2024, 5).unwrap_err();
+    assert!(matches!(err, LabPulseError::Validation(_)));
+}
+
+#[test]
+fn peak_revenue_month_finds_the_clear_peak() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES
+            (1, 2023, 11, 800), (1, 2024, 2, 1500), (1, 2024, 3, 900),
+            (2, 2024, 1, 400), (2, 2024, 2, 700);
+    ");
+    
+    let peak = get_peak_revenue_month(app.state(), 1).unwrap().unwrap();
+    assert_eq!((peak.year, peak.month, peak.revenue), (2024, 2, 1500.0));
+    assert!(get_peak_revenue_month(app.state(), 3).unwrap().is_none());
+    
+    let all = get_all_peak_revenue_months(app.state()).unwrap();
+    let summary: Vec<(i64, i32, f64)> = all.iter().map(|p| (p.office_id, p.month, p.revenue)).collect();
+    assert_eq!(summary, vec![(1, 2, 1500.0), (2, 2, 700.0)]);
+}
+
+#[test]
+fn volume_revenue_ratio_trend_skips_incomplete_months() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES
+            (1, 2024, 1, 10000), (1, 2024, 2, 12000), (1, 2024, 3, 9000), (1, 2024, 4, 5000);
+        INSERT INTO monthly_volume (office_id, year, month, total_weekly_units) VALUES
+            (1, 2024, 1, 100), (1, 2024, 2, 150), (1, 2024, 3, 0), (1, 2024, 5, 40);
+    ");
+    
+    let trend = get_volume_revenue_ratio_trend(app.state(), 1, 2024).unwrap();
+    let ratios: Vec<(i32, f64)> = trend.iter().map(|r| (r.month, r.revenue_per_unit)).collect();
+    
+    assert_eq!(ratios, vec![(1, 100.0), (2, 80.0)]);
+}
+
+#[test]
+fn data_gaps_report_each_kind_of_mismatch() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES (1, 2024, 1, 100), (1, 2024, 2, 100);
+        INSERT INTO monthly_volume (office_id, year, month) VALUES (1, 2024, 1), (1, 2024, 3);
+    ");
+    
+    let gaps = find_data_gaps(app.state()).unwrap();
+    let summary: Vec<(i32, bool, bool)> = gaps.iter().map(|g| (g.month, g.has_financials, g.has_volume)).collect();
+    
+    assert_eq!(summary, vec![(2, true, false), (3, false, true)]);
+}
+
+#[test]
+fn previous_month_rolls_back_over_the_year() {
+    assert_eq!(previous_month(2024, 1), (2023, 12));
+    assert_eq!(previous_month(2024, 7), (2024, 6));
+}
+
+#[test]
+fn rank_change_reports_two_offices_swapping() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES
+            (1, 2023, 12, 2000), (2, 2023, 12, 1000),
+            (1, 2024, 1, 1000), (2, 2024, 1, 3000);
+    ");
+    
+    let changes = get_rank_change(app.state(), 2024, 1, "revenue".to_string()).unwrap();
+    let summary: Vec<(i64, i32, Option<i32>, Option<i32>)> = changes
+        .iter()
+        .map(|c| (c.office_id, c.current_rank, c.previous_rank, c.change))
+        .collect();
+    
+    assert_eq!(summary, vec![(2, 1, Some(2), Some(1)), (1, 2, Some(1), Some(-1))]);
+}
+
+#[test]
+fn health_score_drops_when_lab_ratio_worsens() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside) VALUES
+            (1, 2024, 1, 10000, 1500), (1, 2024, 2, 10000, 2500);
+    ");
+    
+    let before = get_office_health_score(app.state(), 1, 2024, 1).unwrap();
+    let after = get_office_health_score(app.state(), 1, 2024, 2).unwrap();
+    let lab_sub_score = |score: &HealthScore| score.components[0].sub_score.unwrap();
+    
+    assert_eq!(before.components[0].component, "lab_exp_percent");
+    assert!((lab_sub_score(&before) - 75.0).abs() < 1e-9);
+    assert!((lab_sub_score(&after) - 25.0).abs() < 1e-9);
+    assert!(after.score.unwrap() < before.score.unwrap());
+}
+
+#[test]
+fn most_improved_flips_sign_for_lower_is_better_metrics() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside) VALUES
+            (1, 2023, 12, 1000, 200), (1, 2024, 1, 1000, 100),
+            (2, 2023, 12, 1000, 150), (2, 2024, 1, 1000, 200),
+            (3, 2023, 12, 1000, 300), (3, 2024, 1, 1000, 250);
+    ");
+    
+    let improved = get_most_improved(app.state(), 2024, 1, "lab_exp_percent".to_string(), 10).unwrap();
+    let summary: Vec<(i64, f64, f64)> = improved.iter().map(|i| (i.office_id, i.change, i.improvement)).collect();
+    
+    assert_eq!(summary, vec![(1, -10.0, 10.0), (3, -5.0, 5.0)]);
+    
+    let top = get_most_improved(app.state(), 2024, 1, "lab_exp_percent".to_string(), 1).unwrap();
+    assert_eq!(top.len(), 1);
+}
+
+#[test]
+fn forecast_averages_a_steady_series() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES
+            (1, 2023, 11, 1000), (1, 2023, 12, 1000), (1, 2024, 1, 1000), (1, 2024, 2, 1000);
+    ");
+    
+    assert_eq!(forecast_next_month(app.state(), 1, "revenue".to_string(), 3).unwrap(), Some(1000.0));
+    assert_eq!(forecast_next_month(app.state(), 1, "revenue".to_string(), 5).unwrap(), None);
+    assert_eq!(forecast_next_month(app.state(), 2, "revenue".to_string(), 3).unwrap(), None);
+}
+
+#[test]
+fn importing_the_same_file_twice_warns_on_the_second_run() {
+    let app = test_app();
+    seed(&app, "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');");
+    let path = xlsx_file("labpulse_duplicate_import.xlsx", "monthly_financials", &[
+        &["office_id", "year", "month", "revenue"],
+        &["1", "2024", "1", "1000"],
+    ]);
+    
+    let first = import_bulk_financials(app.state(), path.clone()).unwrap();
+    assert!(first.warnings.iter().all(|w| !w.contains("already imported")));
+    
+    let second = import_bulk_financials(app.state(), path).unwrap();
+    assert!(second.warnings.iter().any(|w| w.starts_with("This file was already imported (bulk_financials)")));
+    assert_eq!(second.rows_updated, 1);
+}
+
+#[test]
+fn overtime_recon_series_shows_a_consistent_offset() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_ops (office_id, year, month, overtime_value) VALUES
+            (1, 2024, 1, 600), (1, 2024, 2, 750), (1, 2024, 3, 900);
+        INSERT INTO monthly_financials (office_id, year, month, overtime_exp) VALUES
+            (1, 2024, 1, 500), (1, 2024, 2, 650), (1, 2024, 3, 800), (1, 2024, 4, 300);
+    ");
+    
+    let series = get_overtime_recon_series(app.state(), 1, 2024).unwrap();
+    let differences: Vec<(i32, Option<f64>)> = series.iter().map(|p| (p.month, p.difference)).collect();
+    
+    assert_eq!(differences, vec![(1, Some(100.0)), (2, Some(100.0)), (3, Some(100.0)), (4, None)]);
+}
+
+#[test]
+fn volume_grouping_splits_lab_clinic_and_tiers() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_volume (office_id, year, month, lab_setups, lab_finishes,
+                                    clinic_delivery, clinic_on_hold, economy_units, premium_units)
+            VALUES (1, 2024, 3, 10, 10, 25, 5, 30, 20);
+    ");
+    
+    let grouping = get_volume_grouping(app.state(), 1, 2024, 3).unwrap();
+    
+    assert_eq!(grouping.overall_total, 100);
+    assert_eq!((grouping.lab.total, grouping.lab.percent), (20, Some(20.0)));
+    assert_eq!((grouping.clinic.total, grouping.clinic.percent), (30, Some(30.0)));
+    assert_eq!((grouping.product_tiers.total, grouping.product_tiers.percent), (50, Some(50.0)));
+    
+    let err = get_volume_grouping(app.state(), 1, 2024, 4).unwrap_err();
+    assert!(matches!(err, LabPulseError::NotFound(_)));
+}
+
+#[test]
+fn expense_anomalies_flag_only_the_unexplained_jump() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, personnel_exp) VALUES
+            (1, 2024, 1, 10000, 5000), (1, 2024, 2, 10000, 6000),
+            (2, 2024, 1, 10000, 5000), (2, 2024, 2, 10000, 5100),
+            (3, 2024, 1, 10000, 5000), (3, 2024, 2, 15000, 9000);
+    ");
+    
+    let anomalies = find_expense_anomalies(app.state(), 2024, 2).unwrap();
+    
+    assert_eq!(anomalies.len(), 1);
+    assert_eq!(anomalies[0].office_id, 1);
+    assert_eq!(anomalies[0].previous_expense_percent, 50.0);
+    assert_eq!(anomalies[0].current_expense_percent, 60.0);
+    assert_eq!(anomalies[0].revenue_change_percent, 0.0);
+}
+
+#[test]
+fn saved_view_round_trips_and_overwrites_by_name() {
+    let app = test_app();
+    let filters = r#"{"model":"PO","offices":[1,2]}"#;
+    
+    let id = save_view(app.state(), " Exec ".to_string(), filters.to_string()).unwrap();
+    let view = get_view(app.state(), id).unwrap().unwrap();
+    
+    assert_eq!(view.name, "Exec");
+    assert_eq!(view.filters, serde_json::json!({"model": "PO", "offices": [1, 2]}));
+    
+    let same_id = save_view(app.state(), "Exec".to_string(), r#"{"model":"LO"}"#.to_string()).unwrap();
+    assert_eq!(same_id, id);
+    assert_eq!(get_view(app.state(), id).unwrap().unwrap().filters["model"], "LO");
+    
+    assert!(save_view(app.state(), "Bad".to_string(), "[1, 2]".to_string()).is_err());
+    assert!(get_view(app.state(), id + 1).unwrap().is_none());
+}
+
+#[test]
+fn company_outside_lab_trend_sums_offices_across_a_year_boundary() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, lab_exp_with_outside, lab_exp_no_outside) VALUES
+            (1, 2023, 12, 1000, 800), (2, 2023, 12, 1000, 900),
+            (1, 2024, 2, 1000, 750),
+            (1, 2024, 3, 5000, 0);
+    ");
+    
+    let trend = get_company_outside_lab_trend(app.state(), 2023, 12, 2024, 2).unwrap();
+    let summary: Vec<(i32, i32, f64, Option<f64>, i64)> = trend
+        .iter()
+        .map(|m| (m.year, m.month, m.value, m.percent_of_lab_expense, m.offices_reporting))
+        .collect();
+    
+    assert_eq!(summary, vec![
+        (2023, 12, 300.0, Some(15.0), 2),
+        (2024, 1, 0.0, None, 0),
+        (2024, 2, 250.0, Some(25.0), 1),
+    ]);
+}
+
+#[test]
+fn missing_rollups_report_a_deleted_monthly_row() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO weekly_volume (office_id, year, week_number, economy_units) VALUES
+            (1, 2024, 2, 5), (1, 2024, 10, 7);
+        INSERT INTO monthly_volume (office_id, year, month, economy_units) VALUES
+            (1, 2024, 1, 5), (1, 2024, 3, 7);
+    ");
+    assert!(find_missing_rollups(app.state()).unwrap().is_empty());
+    
+    seed(&app, "DELETE FROM monthly_volume WHERE month = 3;");
+    
+    let missing = find_missing_rollups(app.state()).unwrap();
+    assert_eq!(missing, vec![Period { office_id: 1, year: 2024, month: 3 }]);
+}
+
+#[test]
+fn seasonal_index_over_two_years_of_data() {
+    let app = test_app();
+    seed(&app, "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');");
+    
+    // Flat 1000 a month except a slow July and a busy December in both years
+    let rows: Vec<String> = [2023, 2024]
+        .iter()
+        .flat_map(|year| (1..=12).map(move |month| {
+            let revenue = match month { 7 => 700, 12 => 1300, _ => 1000 };
+            format!("(1, {}, {}, {})", year, month, revenue)
+        }))
+        .collect();
+    seed(&app, &format!(
+        "INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES {};",
+        rows.join(", ")
+    ));
+    
+    let indexes = get_seasonal_index(app.state(), 1).unwrap();
+    
+    assert_eq!(indexes.len(), 12);
+    assert!(indexes.iter().all(|m| m.years_of_data == 2));
+    assert!((indexes[0].index - 1.0).abs() < 1e-9);
+    assert!((indexes[6].index - 0.7).abs() < 1e-9);
+    assert!((indexes[11].index - 1.3).abs() < 1e-9);
+}
+
+#[test]
+fn seasonal_index_needs_two_years() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES (1, 2024, 1, 1000), (1, 2024, 2, 900);
+    ");
+    
+    let err = get_seasonal_index(app.state(), 1).unwrap_err();
+    assert!(matches!(err, LabPulseError::Validation(_)));
+}
+
+#[test]
+fn generate_all_alerts_counts_every_breach_once() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside, overtime_exp) VALUES
+            (1, 2024, 3, 10000, 3000, 600),
+            (2, 2024, 3, 10000, 2200, 100),
+            (3, 2024, 3, 10000, 1000, 100);
+        INSERT INTO monthly_ops (office_id, year, month, backlog_case_count) VALUES
+            (1, 2024, 3, 80), (3, 2024, 3, 10);
+    ");
+    
+    assert_eq!(generate_all_alerts(app.state(), 2024, 3).unwrap(), 4);
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM alerts WHERE office_id = 1 AND severity = 'critical'"), 3);
+    assert_eq!(scalar::<String>(&app, "SELECT severity FROM alerts WHERE office_id = 2"), "warning");
+    
+    // Re-running the close does not duplicate alerts
+    assert_eq!(generate_all_alerts(app.state(), 2024, 3).unwrap(), 0);
+}
+
+#[test]
+fn overtime_share_of_growth_when_overtime_drives_the_increase() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, personnel_exp, overtime_exp, bonus_exp) VALUES
+            (1, 2024, 1, 10000, 500, 0),
+            (1, 2024, 2, 10200, 1300, 0),
+            (1, 2024, 3, 10000, 1000, 0);
+    ");
+    
+    let share = get_overtime_share_of_growth(app.state(), 1, 2024, 2).unwrap().unwrap();
+    assert!((share - 0.8).abs() < 1e-9);
+    
+    // Labor shrank, and there is no prior month before January
+    assert_eq!(get_overtime_share_of_growth(app.state(), 1, 2024, 3).unwrap(), None);
+    assert_eq!(get_overtime_share_of_growth(app.state(), 1, 2024, 1).unwrap(), None);
+}
+
+#[test]
+fn comparison_matrix_puts_offices_in_columns_and_metrics_in_rows() {
+    use calamine::{open_workbook, Data, Reader, Xlsx};
+    
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside) VALUES
+            (1, 2024, 3, 10000, 2000), (3, 2024, 3, 8000, 1000);
+        INSERT INTO monthly_ops (office_id, year, month, backlog_case_count) VALUES (1, 2024, 3, 40);
+    ");
+    let output = std::env::temp_dir().join("labpulse_comparison_matrix.xlsx").to_string_lossy().to_string();
+    
+    export_comparison_matrix_xlsx(app.state(), vec![3, 1, 2], 2024, 3, output.clone()).unwrap();
+    
+    let mut workbook: Xlsx<_> = open_workbook(&output).unwrap();
+    let range = workbook.worksheet_range("2024-03").unwrap();
+    let cell = |row: u32, col: u32| range.get_value((row, col)).cloned().unwrap_or(Data::Empty);
+    
+    assert_eq!(range.get_size(), (1 + COMPARISON_MATRIX_ROWS.len(), 4));
+    assert_eq!(cell(0, 1), Data::String("East (3)".to_string()));
+    assert_eq!(cell(0, 2), Data::String("North (1)".to_string()));
+    assert_eq!(cell(0, 3), Data::String("South (2)".to_string()));
+    assert_eq!(cell(1, 0), Data::String("Revenue".to_string()));
+    assert_eq!(cell(1, 1), Data::Float(8000.0));
+    assert_eq!(cell(2, 2), Data::Float(20.0));
+    assert_eq!(cell(5, 2), Data::Float(40.0));
+    assert_eq!(cell(1, 3), Data::Empty);
+    
+    let err = export_comparison_matrix_xlsx(app.state(), vec![9], 2024, 3, output).unwrap_err();
+    assert!(matches!(err, LabPulseError::NotFound(_)));
+}
+
+#[test]
+fn breach_streak_stops_at_the_first_good_month() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside) VALUES
+            (1, 2024, 1, 1000, 150), (1, 2024, 2, 1000, 250), (1, 2024, 3, 1000, 300), (1, 2024, 4, 1000, 220);
+    ");
+    let streak = |month: i32| {
+        get_breach_streak(app.state(), 1, "lab_exp_percent".to_string(), 20.0, "above".to_string(), 2024, month).unwrap()
+    };
+    
+    assert_eq!(streak(4), 3);
+    assert_eq!(streak(2), 1);
+    assert_eq!(streak(1), 0);
+    // May has no data, so the streak is broken
+    assert_eq!(streak(5), 0);
+}
+
+#[test]
+fn ytd_vs_prior_compares_the_same_months_of_each_year() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside) VALUES
+            (1, 2023, 1, 1000, 200), (1, 2023, 2, 1000, 200), (1, 2023, 3, 9000, 0),
+            (1, 2024, 1, 1500, 150), (1, 2024, 2, 1500, 150), (1, 2024, 3, 9000, 0);
+    ");
+    
+    let comparison = get_ytd_vs_prior(app.state(), 1, 2024, 2).unwrap();
+    
+    assert_eq!(comparison.current.totals.revenue, 3000.0);
+    assert_eq!(comparison.prior.totals.revenue, 2000.0);
+    assert_eq!(comparison.revenue_delta, 1000.0);
+    assert_eq!(comparison.revenue_change_percent, Some(50.0));
+    assert_eq!(comparison.lab_exp_percent_delta, Some(-10.0));
+}
+
+#[test]
+fn standardization_progress_excludes_inactive_offices_from_completion() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model, standardization_status) VALUES
+            (1, 'North', 'PO', 'Standardized'),
+            (2, 'South', 'PO', 'Standardized'),
+            (3, 'East', 'PO', 'In Progress'),
+            (4, 'West', 'PO', 'Inactive'),
+            (5, 'Central', 'PO', NULL);
+    ");
+    
+    let progress = get_standardization_progress(app.state()).unwrap();
+    let statuses: Vec<(&str, i64)> = progress.statuses.iter().map(|s| (s.status.as_str(), s.count)).collect();
+    
+    assert_eq!(progress.total_offices, 5);
+    assert_eq!(progress.active_offices, 4);
+    assert_eq!(progress.standardized_offices, 2);
+    assert_eq!(progress.percent_complete, 50.0);
+    assert_eq!(statuses, vec![("Standardized", 2), ("In Progress", 1), ("Inactive", 1), ("Unspecified", 1)]);
+    assert_eq!(progress.statuses[0].percent, 40.0);
+}
+
+#[test]
+fn variance_to_baseline_compares_budget_with_actuals() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, personnel_exp) VALUES (1, 2024, 3, 11000, 4500);
+    ");
+    
+    set_baseline(app.state(), 1, "Budget".to_string(), 3, "revenue".to_string(), 9000.0).unwrap();
+    set_baseline(app.state(), 1, "Budget".to_string(), 3, "revenue".to_string(), 10000.0).unwrap();
+    set_baseline(app.state(), 1, "Budget".to_string(), 3, "personnel_exp".to_string(), 5000.0).unwrap();
+    set_baseline(app.state(), 1, "Budget".to_string(), 3, "overtime_exp".to_string(), 300.0).unwrap();
+    
+    let variances = get_variance_to_baseline(app.state(), 1, 2024, 3, " Budget ".to_string()).unwrap();
+    let summary: Vec<(&str, f64, Option<f64>)> = variances
+        .iter()
+        .map(|v| (v.field.as_str(), v.baseline, v.variance))
+        .collect();
+    
+    assert_eq!(summary, vec![
+        ("overtime_exp", 300.0, None),
+        ("personnel_exp", 5000.0, Some(-500.0)),
+        ("revenue", 10000.0, Some(1000.0)),
+    ]);
+    
+    let err = set_baseline(app.state(), 1, "Budget".to_string(), 3, "office_name".to_string(), 1.0).unwrap_err();
+    assert!(matches!(err, LabPulseError::Validation(_)));
+    let err = get_variance_to_baseline(app.state(), 1, 2024, 3, "Stretch".to_string()).unwrap_err();
+    assert!(matches!(err, LabPulseError::NotFound(_)));
+}
+
+#[test]
+fn markup_is_revenue_per_lab_dollar() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside) VALUES
+            (1, 2024, 3, 10000, 2500), (2, 2024, 3, 9000, 4500), (3, 2024, 3, 5000, 0);
+    ");
+    
+    assert_eq!(get_markup(app.state(), 1, 2024, 3).unwrap(), Some(4.0));
+    assert_eq!(get_markup(app.state(), 3, 2024, 3).unwrap(), None);
+    
+    let distribution = get_markup_distribution(app.state(), 2024, 3).unwrap();
+    let offices: Vec<(i64, f64)> = distribution.offices.iter().map(|o| (o.office_id, o.markup)).collect();
+    assert_eq!(offices, vec![(2, 2.0), (1, 4.0)]);
+    assert_eq!((distribution.min, distribution.max, distribution.median), (Some(2.0), Some(4.0), Some(3.0)));
+}
+
+#[test]
+fn company_periods_count_offices_per_data_kind() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES
+            (1, 2024, 1, 1000), (2, 2024, 1, 1000), (1, 2024, 2, 1000);
+        INSERT INTO monthly_ops (office_id, year, month, backlog_case_count) VALUES (2, 2024, 1, 10);
+        INSERT INTO monthly_volume (office_id, year, month) VALUES (1, 2024, 2), (2, 2024, 2);
+    ");
+    
+    let periods = get_company_periods(app.state()).unwrap();
+    let summary: Vec<(i32, i64, i64, i64)> = periods
+        .iter()
+        .map(|p| (p.month, p.financial_offices, p.ops_offices, p.volume_offices))
+        .collect();
+    
+    assert_eq!(summary, vec![(1, 2, 1, 0), (2, 1, 0, 2)]);
+}
+
+#[test]
+fn tier_trend_charts_premium_units_by_month() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_volume (office_id, year, month, premium_units, economy_units) VALUES
+            (1, 2024, 1, 12, 40), (1, 2024, 2, 15, 40), (1, 2024, 4, 0, 40), (1, 2023, 12, 99, 40);
+    ");
+    
+    let trend = get_tier_trend(app.state(), 1, "premium".to_string(), 2024).unwrap();
+    
+    assert_eq!(trend.len(), 12);
+    assert_eq!(&trend[..5], &[Some(12), Some(15), None, Some(0), None]);
+    assert!(trend[4..].iter().all(Option::is_none));
+    
+    let err = get_tier_trend(app.state(), 1, "platinum".to_string(), 2024).unwrap_err();
+    assert!(matches!(err, LabPulseError::Validation(_)));
+}
+
+#[test]
+fn overtime_per_backlog_divides_and_guards_zero_backlog() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, overtime_exp) VALUES (1, 2024, 1, 1200), (1, 2024, 2, 800);
+        INSERT INTO monthly_ops (office_id, year, month, backlog_case_count) VALUES (1, 2024, 1, 40), (1, 2024, 2, 0);
+    ");
+    
+    assert_eq!(get_overtime_per_backlog(app.state(), 1, 2024, 1).unwrap(), Some(30.0));
+    assert_eq!(get_overtime_per_backlog(app.state(), 1, 2024, 2).unwrap(), None);
+    assert_eq!(get_overtime_per_backlog(app.state(), 1, 2024, 3).unwrap(), None);
+}
+
+#[test]
+fn future_periods_flag_rows_after_the_current_month() {
+    use chrono::Datelike;
+    
+    let app = test_app();
+    let today = chrono::Local::now().date_naive();
+    seed(&app, &format!(
+        "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+         INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES (1, {}, {}, 1000), (1, 2205, 3, 1000);
+         INSERT INTO monthly_volume (office_id, year, month) VALUES (1, 2205, 4);",
+        today.year(),
+        today.month()
+    ));
+    
+    let future = find_future_periods(app.state()).unwrap();
+    let summary: Vec<(&str, i32, i32)> = future.iter().map(|p| (p.table_name.as_str(), p.year, p.month)).collect();
+    
+    assert_eq!(summary, vec![("monthly_financials", 2205, 3), ("monthly_volume", 2205, 4)]);
+}
+
+#[test]
+fn similar_offices_rank_the_clearly_closest_first() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES
+            (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PLLC'), (4, 'West', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside, personnel_exp, overtime_exp) VALUES
+            (1, 2024, 3, 1000, 200, 300, 20),
+            (2, 2024, 3, 1000, 210, 310, 20),
+            (3, 2024, 3, 1000, 400, 100, 60),
+            (4, 2024, 3, 1000, 300, 200, 40);
+    ");
+    
+    let similar = find_similar_offices(app.state(), 1, 2024, 3, 10).unwrap();
+    let order: Vec<i64> = similar.iter().map(|s| s.office_id).collect();
+    
+    assert_eq!(order, vec![2, 4, 3]);
+    assert!(similar[0].distance < similar[1].distance);
+    assert_eq!(find_similar_offices(app.state(), 1, 2024, 3, 1).unwrap().len(), 1);
+}
+
+#[test]
+fn similar_offices_with_identical_ratios_are_all_at_distance_zero() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside, personnel_exp) VALUES
+            (1, 2024, 3, 1000, 200, 300), (2, 2024, 3, 2000, 400, 600), (3, 2024, 3, 500, 100, 150);
+    ");
+    
+    let similar = find_similar_offices(app.state(), 2, 2024, 3, 10).unwrap();
+    let summary: Vec<(i64, f64)> = similar.iter().map(|s| (s.office_id, s.distance)).collect();
+    
+    assert_eq!(summary, vec![(1, 0.0), (3, 0.0)]);
+}
+
+#[test]
+fn alert_resolution_stats_measure_time_to_dismiss() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO alerts (id, office_id, year, month, alert_type, severity, message, created_at) VALUES
+            (1, 1, 2024, 3, 'backlog_high', 'warning', 'Backlog', datetime('now', '-2 hours')),
+            (2, 1, 2024, 3, 'overtime_high', 'warning', 'Overtime', datetime('now', '-5 hours'));
+        INSERT INTO alerts (office_id, year, month, alert_type, severity, message, is_dismissed) VALUES
+            (1, 2024, 3, 'lab_expense_high', 'warning', 'Dismissed before tracking', 1);
+    ");
+    
+    dismiss_alert(app.state(), 1).unwrap();
+    
+    let stats = get_alert_resolution_stats(app.state(), 2024, 3).unwrap();
+    let average = stats.average_hours.unwrap();
+    
+    assert_eq!(stats.dismissed_count, 1);
+    assert!(average > 1.9 && average < 2.1, "average_hours was {}", average);
+    assert_eq!(stats.max_hours, stats.average_hours);
+}
+
+#[test]
+fn revenue_weighted_metric_leans_toward_larger_offices() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside) VALUES
+            (1, 2024, 3, 9000, 900), (2, 2024, 3, 1000, 300), (3, 2024, 3, 0, 100);
+    ");
+    
+    let weighted = get_revenue_weighted_metric(app.state(), 2024, 3, "lab_exp_percent".to_string()).unwrap();
+    
+    assert_eq!(weighted.offices_included, 2);
+    assert!((weighted.weighted_average.unwrap() - 12.0).abs() < 1e-9);
+    assert!((weighted.unweighted_average.unwrap() - 20.0).abs() < 1e-9);
+}
+
+#[test]
+fn incomplete_office_profiles_skip_the_complete_office() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model, phone, managing_dentist) VALUES
+            (1, 'North', 'PO', '555-0100', 'Dr. Lee'),
+            (2, 'South', 'PO', '  ', 'Dr. Kim'),
+            (3, 'East', 'PO', '555-0300', 'Dr. Park');
+        INSERT INTO office_contacts (office_id, role, name) VALUES (1, 'Lab Manager', 'Ann'), (2, 'Lab Manager', 'Bo');
+    ");
+    
+    let incomplete = find_incomplete_office_profiles(app.state()).unwrap();
+    let ids: Vec<i64> = incomplete.iter().map(|o| o.office_id).collect();
+    
+    // South lacks a phone number and East has no contacts
+    assert_eq!(ids, vec![3, 2]);
+}
+
+#[test]
+fn tier_growth_compares_each_tier_with_last_month() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_volume (office_id, year, month, economy_units, premium_units, repair_units) VALUES
+            (1, 2023, 12, 40, 10, 0), (1, 2024, 1, 50, 8, 3);
+    ");
+    
+    let growth = get_tier_growth(app.state(), 1, 2024, 1).unwrap();
+    let tier = |name: &str| growth.iter().find(|g| g.tier == name).unwrap();
+    
+    assert_eq!(growth.len(), UNIT_TIERS.len());
+    assert_eq!((tier("economy").previous_units, tier("economy").growth_percent), (Some(40), Some(25.0)));
+    assert_eq!((tier("premium").units, tier("premium").growth_percent), (8, Some(-20.0)));
+    assert_eq!((tier("repair").units, tier("repair").growth_percent), (3, None));
+    
+    // No December 2024 volume, so January 2025 has nothing to compare
+    seed(&app, "INSERT INTO monthly_volume (office_id, year, month, economy_units) VALUES (1, 2025, 1, 5);");
+    let growth = get_tier_growth(app.state(), 1, 2025, 1).unwrap();
+    assert!(growth.iter().all(|g| g.previous_units.is_none() && g.growth_percent.is_none()));
+}
+
+#[test]
+fn company_margin_dollars_sum_three_offices() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside, teeth_supplies, personnel_exp, overtime_exp) VALUES
+            (1, 2024, 3, 10000, 2000, 500, 3000, 500),
+            (2, 2024, 3, 6000, 1500, 0, 2000, 0),
+            (3, 2024, 3, 4000, 1000, 500, 3500, 0),
+            (1, 2024, 4, 99999, 0, 0, 0, 0);
+    ");
+    
+    let margin = get_company_margin_dollars(app.state(), 2024, 3).unwrap();
+    
+    assert_eq!(margin.offices_reporting, 3);
+    assert_eq!(margin.revenue, 20000.0);
+    assert_eq!(margin.total_expenses, 14500.0);
+    assert_eq!(margin.margin_dollars, 5500.0);
+    assert!((margin.margin_percent.unwrap() - 27.5).abs() < 1e-9);
+}
+
+#[test]
+fn company_summary_reports_office_count() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, lab_exp_with_outside) VALUES
+            (1, 2024, 3, 10000, 2000),
+            (2, 2024, 3, 6000, 1500),
+            (1, 2024, 4, 99999, 0);
+    ");
+    
+    let summary = get_company_summary(app.state(), 2024, 3).unwrap();
+    
+    assert_eq!(summary.offices_reporting, 2);
+    assert_eq!(summary.totals.revenue, 16000.0);
+    let json = serde_json::to_value(&summary).unwrap();
+    assert_eq!(json["offices_reporting"], 2);
+    assert!(json.get("months_reported").is_none());
+}
+
+#[test]
+fn ranking_snapshot_survives_later_data_edits() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue) VALUES
+            (1, 2024, 3, 3000), (2, 2024, 3, 2000), (3, 2024, 3, 1000);
+    ");
+    
+    assert_eq!(snapshot_rankings(app.state(), 2024, 3, "revenue".to_string()).unwrap(), 3);
+    seed(&app, "UPDATE monthly_financials SET revenue = 9000 WHERE office_id = 3;");
+    
+    let snapshot = get_ranking_snapshot(app.state(), 2024, 3, "revenue".to_string()).unwrap();
+    let ranks: Vec<(i64, i32, f64)> = snapshot.iter().map(|e| (e.office_id, e.rank, e.value)).collect();
+    
+    assert_eq!(ranks, vec![(1, 1, 3000.0), (2, 2, 2000.0), (3, 3, 1000.0)]);
+    assert!(get_ranking_snapshot(app.state(), 2024, 4, "revenue".to_string()).unwrap().is_empty());
+}
+
+#[test]
+fn imported_targets_read_back_by_office_and_year() {
+    let app = test_app();
+    seed(&app, "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO');");
+    let path = xlsx_file("labpulse_targets.xlsx", "targets", &[
+        &["office_id", "year", "month", "metric", "target_value"],
+        &["1", "2024", "2", "Revenue", "12000"],
+        &["1", "2024", "1", "lab_exp_percent", "18.5"],
+        &["1", "2024", "1", "revenue", "10000"],
+        &["2", "2024", "1", "revenue", "8000"],
+        &["1", "2024", "1", "headcount", "5"],
+    ]);
+    
+    let summary = import_bulk_targets(app.state(), path).unwrap();
+    assert_eq!(summary.rows_inserted, 4);
+    assert_eq!(summary.warnings.len(), 1);
+    
+    let targets = get_targets(app.state(), 1, 2024).unwrap();
+    let summary: Vec<(i32, &str, f64)> = targets.iter().map(|t| (t.month, t.metric.as_str(), t.target_value)).collect();
+    
+    assert_eq!(summary, vec![(1, "lab_exp_percent", 18.5), (1, "revenue", 10000.0), (2, "revenue", 12000.0)]);
+    assert!(get_targets(app.state(), 1, 2023).unwrap().is_empty());
+}
+
+#[test]
+fn rework_rate_is_retries_and_remakes_over_total_units() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_volume (office_id, year, month, retry_units, remake_units, total_weekly_units) VALUES
+            (1, 2024, 3, 3, 2, 100), (2, 2024, 3, 1, 0, 50), (3, 2024, 3, 0, 0, 0);
+    ");
+    
+    assert_eq!(get_rework_rate(app.state(), 1, 2024, 3).unwrap(), Some(5.0));
+    assert_eq!(get_rework_rate(app.state(), 3, 2024, 3).unwrap(), None);
+    assert_eq!(get_rework_rate(app.state(), 1, 2024, 4).unwrap(), None);
+    
+    let ranking = rank_by_rework_rate(app.state(), 2024, 3).unwrap();
+    let summary: Vec<(i64, Option<i32>)> = ranking.iter().map(|r| (r.office_id, r.rank)).collect();
+    assert_eq!(summary, vec![(2, Some(1)), (1, Some(2)), (3, None)]);
+}
+
+#[test]
+fn unprofitable_offices_list_losses_worst_first() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO'), (3, 'East', 'PO');
+        INSERT INTO monthly_financials (office_id, year, month, revenue, personnel_exp, lab_exp_with_outside) VALUES
+            (1, 2024, 3, 10000, 6000, 2000),
+            (2, 2024, 3, 5000, 5000, 1000),
+            (3, 2024, 3, 4000, 4000, 2000);
+    ");
+    
+    let offices = find_unprofitable_offices(app.state(), 2024, 3).unwrap();
+    let summary: Vec<(i64, f64, Option<f64>)> = offices.iter().map(|o| (o.office_id, o.margin_dollars, o.margin_percent)).collect();
+    
+    assert_eq!(summary, vec![(3, -2000.0, Some(-50.0)), (2, -1000.0, Some(-20.0))]);
+}
+
+#[test]
+fn staffing_backlog_relationship_tracks_a_growing_gap() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+        INSERT INTO monthly_ops (office_id, year, month, current_staff, required_staff, backlog_case_count) VALUES
+            (1, 2024, 1, 5, 6, 22), (1, 2024, 2, 5, 7, 41), (1, 2024, 3, 4, 7, 58),
+            (1, 2024, 4, 4, 8, 83), (1, 2024, 5, NULL, 8, 500);
+    ");
+    
+    let correlation = get_staffing_backlog_relationship(app.state(), 1).unwrap().unwrap();
+    
+    assert!(correlation > 0.99, "correlation was {}", correlation);
+}
+
+#[test]
+fn week_one_falls_in_january_even_when_it_starts_in_december() {
+    // 2026-W01 runs Mon 2025-12-29 to Sun 2026-01-04; its Thursday is January 1
+    assert_eq!(month_for_week(2020, 1), 1);
+    assert_eq!(month_for_week(2026, 1), 1);
+    assert_eq!(month_week_range(2020, 1), Some((1, 5)));
+    assert_eq!(month_week_range(2026, 1), Some((1, 5)));
+}
+
+#[test]
+fn week_53_maps_to_december_in_long_and_short_years() {
+    // 2020 has 53 ISO weeks (W53's Thursday is Dec 31); 2024 has 52, so W53 is
+    // treated as W52
+    assert_eq!(month_for_week(2020, 53), 12);
+    assert_eq!(month_for_week(2024, 53), 12);
+    assert_eq!(month_for_week(2024, 52), 12);
+    assert_eq!(month_week_range(2020, 12), Some((49, 53)));
+    assert_eq!(month_week_range(2024, 12), Some((49, 53)));
+    assert_eq!(month_week_range(2024, 11), Some((45, 48)));
+}
+
+#[test]
+fn rebuild_all_aggregates_applies_the_current_aggregation_method() {
+    let app = test_app();
+    seed(&app, "
+        INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO'), (2, 'South', 'PO');
+        INSERT INTO weekly_volume (office_id, year, week_number, economy_units, lab_setups) VALUES
+            (1, 2024, 2, 4, 10), (1, 2024, 3, 6, 20), (1, 2024, 10, 3, 0), (2, 2024, 2, 8, 0);
+    ");
+    let units = |office_id: i64, month: i32| -> (i64, i64) {
+        app.state::<DbConnection>().0.get().unwrap().query_row(
+            "SELECT economy_units, lab_setups FROM monthly_volume WHERE office_id = ?1 AND year = 2024 AND month = ?2",
+            params![office_id, month],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        ).unwrap()
+    };
+    
+    assert_eq!(rebuild_all_aggregates(app.state()).unwrap(), 3);
+    assert_eq!(units(1, 1), (5, 15));
+    assert_eq!(units(1, 3), (3, 0));
+    
+    // Switching to sum only takes effect on existing months after a full rebuild
+    seed(&app, "INSERT INTO settings (key, value) VALUES ('weekly_aggregation_method', 'sum');");
+    assert_eq!(units(1, 1), (5, 15));
+    rebuild_all_aggregates(app.state()).unwrap();
+    assert_eq!(units(1, 1), (10, 15));
+    assert_eq!(units(2, 1), (8, 0));
+}
+
+#[test]
+fn bulk_operations_aborts_when_most_offices_are_unknown() {
+    let app = test_app();
+    seed(&app, "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');");
+    let path = xlsx_file("labpulse_ops_unknown_offices.xlsx", "monthly_ops", &[
+        &["office_id", "year", "month", "backlog_case_count"],
+        &["1", "2024", "1", "10"],
+        &["7", "2024", "1", "10"],
+        &["8", "2024", "1", "10"],
+    ]);
+    
+    let err = import_bulk_operations(app.state(), path).unwrap_err();
+    
+    assert!(matches!(err, LabPulseError::Import(_)));
+    assert!(err.to_string().contains("Import the offices file first"));
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM monthly_ops"), 0);
+    assert_eq!(scalar::<i64>(&app, "SELECT COUNT(*) FROM import_log"), 0);
+}
+
+#[test]
+fn bulk_operations_keeps_stored_staffing_for_six_column_files() {
+    let app = test_app();
+    seed(&app, "INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO');
+                INSERT INTO monthly_ops (office_id, year, month, backlog_case_count, current_staff, required_staff)
+                VALUES (1, 2024, 3, 40, 3, 4);");
+    let path = xlsx_file("labpulse_ops_six_columns.xlsx", "monthly_ops", &[
+        &["office_id", "year", "month", "backlog_case_count", "overtime_value", "labor_model_value"],
+        &["1", "2024", "3", "25", "5.5", "12"],
+    ]);
+    
+    let summary = import_bulk_operations(app.state(), path).unwrap();
+    
+    assert_eq!(summary.rows_updated, 1);
+    assert_eq!(scalar::<i64>(&app, "SELECT backlog_case_count FROM monthly_ops"), 25);
+    assert_eq!(scalar::<f64>(&app, "SELECT current_staff FROM monthly_ops"), 3.0);
+    assert_eq!(scalar::<f64>(&app, "SELECT required_staff FROM monthly_ops"), 4.0);
+}
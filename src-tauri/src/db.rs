@@ -1,9 +1,16 @@
+use crate::error::LabPulseError;
+use r2d2_sqlite::SqliteConnectionManager;
 use rusqlite::{Connection, Result};
 use serde::{Deserialize, Serialize};
 use tauri::Manager;
 
+pub type DbPool = r2d2::Pool<SqliteConnectionManager>;
+
+// Enough for a long import to run alongside dashboard reads
+const POOL_SIZE: u32 = 4;
+
 // Database initialization and migrations
-pub fn init_db(app_handle: &tauri::AppHandle) -> Result<Connection> {
+pub fn init_db(app_handle: &tauri::AppHandle) -> std::result::Result<DbPool, LabPulseError> {
     let app_dir = app_handle.path().app_data_dir()
         .expect("Failed to get app data directory");
     
@@ -11,18 +18,157 @@ pub fn init_db(app_handle: &tauri::AppHandle) -> Result<Connection> {
         .expect("Failed to create app data directory");
     
     let db_path = app_dir.join("labpulse.db");
-    let conn = Connection::open(db_path)?;
     
-    // Run migrations
+    // Foreign keys and the busy timeout are per-connection, so every pooled
+    // connection sets them. WAL lets readers proceed while an import writes.
+    let manager = SqliteConnectionManager::file(db_path).with_init(|conn| {
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             PRAGMA foreign_keys = ON;
+             PRAGMA busy_timeout = 5000;",
+        )
+    });
+    let pool = r2d2::Pool::builder().max_size(POOL_SIZE).build(manager)?;
+    
+    // Run migrations on the first connection
+    let conn = pool.get()?;
     run_migrations(&conn)?;
     
-    Ok(conn)
+    Ok(pool)
+}
+
+// One schema change, applied once in version order and recorded in schema_migrations
+struct Migration {
+    version: i64,
+    description: &'static str,
+    apply: fn(&Connection) -> Result<()>,
+}
+
+// Every schema change, oldest first. Append new changes with the next version
+// number; never edit or renumber one that has shipped.
+fn migrations() -> Vec<Migration> {
+    vec![
+        Migration {
+            version: 1,
+            description: "base schema",
+            apply: create_base_schema,
+        },
+        Migration {
+            version: 2,
+            description: "staffing columns on monthly_ops",
+            apply: |conn| {
+                conn.execute("ALTER TABLE monthly_ops ADD COLUMN current_staff REAL", [])?;
+                conn.execute("ALTER TABLE monthly_ops ADD COLUMN required_staff REAL", [])?;
+                conn.execute("ALTER TABLE monthly_ops ADD COLUMN staffing_trend REAL", [])?;
+                Ok(())
+            },
+        },
+        Migration {
+            version: 3,
+            description: "content_hash on import_log for duplicate-import detection",
+            apply: |conn| {
+                conn.execute("ALTER TABLE import_log ADD COLUMN content_hash TEXT", [])?;
+                conn.execute("CREATE INDEX IF NOT EXISTS idx_import_log_hash ON import_log(content_hash)", [])?;
+                Ok(())
+            },
+        },
+        Migration {
+            version: 4,
+            description: "dismissed_at on alerts for resolution-time reporting",
+            apply: |conn| {
+                conn.execute("ALTER TABLE alerts ADD COLUMN dismissed_at TIMESTAMP", [])?;
+                Ok(())
+            },
+        },
+    ]
 }
 
-fn run_migrations(conn: &Connection) -> Result<()> {
+// Column-adding migrations that ran unversioned, on every startup, before
+// schema_migrations existed
+const LEGACY_COLUMN_MIGRATIONS: [(i64, &str, &str); 3] = [
+    (2, "monthly_ops", "current_staff"),
+    (3, "import_log", "content_hash"),
+    (4, "alerts", "dismissed_at"),
+];
+
+pub(crate) fn run_migrations(conn: &Connection) -> Result<()> {
     // Enable foreign keys
     conn.execute("PRAGMA foreign_keys = ON", [])?;
     
+    let migrations = migrations();
+    let versioned: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'schema_migrations')",
+        [],
+        |row| row.get(0),
+    )?;
+    
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )?;
+    
+    // A database from before versioning may already have some columns; mark
+    // those migrations applied so their ALTERs don't fail on a duplicate column.
+    // The base schema is safe to re-run and always goes through the runner.
+    if !versioned {
+        for (version, table, column) in LEGACY_COLUMN_MIGRATIONS {
+            let has_column: bool = conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2)",
+                [table, column],
+                |row| row.get(0),
+            )?;
+            if has_column {
+                let description = migrations
+                    .iter()
+                    .find(|m| m.version == version)
+                    .map(|m| m.description)
+                    .unwrap_or_default();
+                conn.execute(
+                    "INSERT OR IGNORE INTO schema_migrations (version, description) VALUES (?1, ?2)",
+                    rusqlite::params![version, description],
+                )?;
+            }
+        }
+    }
+    
+    for migration in &migrations {
+        let applied: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM schema_migrations WHERE version = ?1)",
+            [migration.version],
+            |row| row.get(0),
+        )?;
+        if applied {
+            continue;
+        }
+        
+        // Each migration and its version record commit together
+        let tx = conn.unchecked_transaction()?;
+        (migration.apply)(&tx)?;
+        tx.execute(
+            "INSERT INTO schema_migrations (version, description) VALUES (?1, ?2)",
+            rusqlite::params![migration.version, migration.description],
+        )?;
+        tx.commit()?;
+    }
+    
+    Ok(())
+}
+
+// Highest migration version recorded; 0 if schema_migrations is empty
+pub(crate) fn schema_version(conn: &Connection) -> Result<i64> {
+    conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_migrations",
+        [],
+        |row| row.get(0),
+    )
+}
+
+// Version 1: every table and index that predates schema versioning
+fn create_base_schema(conn: &Connection) -> Result<()> {
     // Create offices table
     conn.execute(
         "CREATE TABLE IF NOT EXISTS offices (
@@ -241,6 +387,85 @@ fn run_migrations(conn: &Connection) -> Result<()> {
         [],
     )?;
     
+    // Create count_history table for tracking database growth
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS count_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            offices INTEGER NOT NULL,
+            staff INTEGER NOT NULL,
+            contacts INTEGER NOT NULL,
+            financials INTEGER NOT NULL,
+            ops INTEGER NOT NULL,
+            volume INTEGER NOT NULL,
+            notes INTEGER NOT NULL,
+            alerts INTEGER NOT NULL,
+            recorded_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )?;
+    
+    // Create saved_views table for user-defined filter presets
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS saved_views (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT UNIQUE NOT NULL,
+            filters_json TEXT NOT NULL,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )?;
+    
+    // Create financial_baselines table for named budget scenarios
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS financial_baselines (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            office_id INTEGER NOT NULL,
+            scenario_name TEXT NOT NULL,
+            month INTEGER NOT NULL CHECK(month BETWEEN 1 AND 12),
+            field TEXT NOT NULL,
+            value REAL NOT NULL,
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(office_id, scenario_name, month, field),
+            FOREIGN KEY (office_id) REFERENCES offices(office_id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+    
+    // Create ranking_snapshots table so month rankings survive later data edits
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS ranking_snapshots (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            office_id INTEGER NOT NULL,
+            year INTEGER NOT NULL,
+            month INTEGER NOT NULL,
+            metric TEXT NOT NULL,
+            rank INTEGER NOT NULL,
+            value REAL NOT NULL,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(office_id, year, month, metric),
+            FOREIGN KEY (office_id) REFERENCES offices(office_id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+    
+    // Create monthly_targets table for per-office budget targets on a metric
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS monthly_targets (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            office_id INTEGER NOT NULL,
+            year INTEGER NOT NULL,
+            month INTEGER NOT NULL,
+            metric TEXT NOT NULL,
+            target_value REAL NOT NULL,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(office_id, year, month, metric),
+            FOREIGN KEY (office_id) REFERENCES offices(office_id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+    
     // Create indexes
     conn.execute("CREATE INDEX IF NOT EXISTS idx_staff_office ON staff(office_id)", [])?;
     conn.execute("CREATE INDEX IF NOT EXISTS idx_contacts_office ON office_contacts(office_id)", [])?;
@@ -252,20 +477,6 @@ fn run_migrations(conn: &Connection) -> Result<()> {
     conn.execute("CREATE INDEX IF NOT EXISTS idx_alerts_office_date ON alerts(office_id, year, month)", [])?;
     conn.execute("CREATE INDEX IF NOT EXISTS idx_alerts_dismissed ON alerts(is_dismissed)", [])?;
     
-    // Migration: Add staffing tracking columns to monthly_ops table
-    // Check if columns exist before adding (SQLite doesn't support IF NOT EXISTS for ALTER TABLE)
-    let has_current_staff: bool = conn.query_row(
-        "SELECT COUNT(*) FROM pragma_table_info('monthly_ops') WHERE name='current_staff'",
-        [],
-        |row| row.get::<_, i64>(0).map(|count| count > 0)
-    ).unwrap_or(false);
-    
-    if !has_current_staff {
-        conn.execute("ALTER TABLE monthly_ops ADD COLUMN current_staff REAL", [])?;
-        conn.execute("ALTER TABLE monthly_ops ADD COLUMN required_staff REAL", [])?;
-        conn.execute("ALTER TABLE monthly_ops ADD COLUMN staffing_trend REAL", [])?;
-    }
-    
     Ok(())
 }
 
@@ -317,6 +528,61 @@ pub fn get_table_counts(conn: &Connection) -> Result<TableCounts> {
     })
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CountSnapshot {
+    pub id: i64,
+    pub counts: TableCounts,
+    pub recorded_at: String,
+}
+
+pub fn record_counts_snapshot(conn: &Connection) -> Result<CountSnapshot> {
+    let counts = get_table_counts(conn)?;
+    
+    conn.execute(
+        "INSERT INTO count_history (offices, staff, contacts, financials, ops, volume, notes, alerts)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        rusqlite::params![
+            counts.offices, counts.staff, counts.contacts, counts.financials,
+            counts.ops, counts.volume, counts.notes, counts.alerts
+        ],
+    )?;
+    
+    let id = conn.last_insert_rowid();
+    let recorded_at: String = conn.query_row(
+        "SELECT recorded_at FROM count_history WHERE id = ?1",
+        [id],
+        |row| row.get(0),
+    )?;
+    
+    Ok(CountSnapshot { id, counts, recorded_at })
+}
+
+pub fn get_count_history(conn: &Connection) -> Result<Vec<CountSnapshot>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, offices, staff, contacts, financials, ops, volume, notes, alerts, recorded_at
+         FROM count_history ORDER BY recorded_at, id"
+    )?;
+    
+    let snapshots = stmt.query_map([], |row| {
+        Ok(CountSnapshot {
+            id: row.get(0)?,
+            counts: TableCounts {
+                offices: row.get(1)?,
+                staff: row.get(2)?,
+                contacts: row.get(3)?,
+                financials: row.get(4)?,
+                ops: row.get(5)?,
+                volume: row.get(6)?,
+                notes: row.get(7)?,
+                alerts: row.get(8)?,
+            },
+            recorded_at: row.get(9)?,
+        })
+    })?;
+    
+    snapshots.collect()
+}
+
 pub fn get_all_offices(conn: &Connection) -> Result<Vec<Office>> {
     let mut stmt = conn.prepare(
         "SELECT office_id, office_name, model, address, phone, managing_dentist, dfo, standardization_status 
@@ -339,3 +605,68 @@ pub fn get_all_offices(conn: &Connection) -> Result<Vec<Office>> {
     offices.collect()
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn migrated_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn
+    }
+    
+    fn applied_versions(conn: &Connection) -> Vec<i64> {
+        let mut stmt = conn.prepare("SELECT version FROM schema_migrations ORDER BY version").unwrap();
+        let versions = stmt.query_map([], |row| row.get(0)).unwrap().collect::<Result<Vec<_>>>().unwrap();
+        versions
+    }
+    
+    #[test]
+    fn migrations_are_recorded_once_and_safe_to_rerun() {
+        let conn = migrated_connection();
+        run_migrations(&conn).unwrap();
+        
+        assert_eq!(applied_versions(&conn), vec![1, 2, 3, 4]);
+        assert_eq!(schema_version(&conn).unwrap(), 4);
+    }
+    
+    #[test]
+    fn legacy_database_skips_alters_for_columns_it_already_has() {
+        // Base tables plus the staffing columns the old startup code added,
+        // but no schema_migrations table yet
+        let conn = Connection::open_in_memory().unwrap();
+        create_base_schema(&conn).unwrap();
+        conn.execute_batch(
+            "ALTER TABLE monthly_ops ADD COLUMN current_staff REAL;
+             ALTER TABLE monthly_ops ADD COLUMN required_staff REAL;
+             ALTER TABLE monthly_ops ADD COLUMN staffing_trend REAL;",
+        ).unwrap();
+        
+        // Re-running version 2 would fail on the duplicate column
+        run_migrations(&conn).unwrap();
+        
+        assert_eq!(applied_versions(&conn), vec![1, 2, 3, 4]);
+        let has_content_hash: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM pragma_table_info('import_log') WHERE name = 'content_hash')",
+            [],
+            |row| row.get(0),
+        ).unwrap();
+        assert!(has_content_hash);
+    }
+    
+    #[test]
+    fn count_history_returns_snapshots_in_order() {
+        let conn = migrated_connection();
+        
+        let first = record_counts_snapshot(&conn).unwrap();
+        conn.execute("INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO')", []).unwrap();
+        let second = record_counts_snapshot(&conn).unwrap();
+        
+        let history = get_count_history(&conn).unwrap();
+        
+        assert_eq!(history.len(), 2);
+        assert_eq!((history[0].id, history[0].counts.offices), (first.id, 0));
+        assert_eq!((history[1].id, history[1].counts.offices), (second.id, 1));
+    }
+}
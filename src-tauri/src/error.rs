@@ -0,0 +1,47 @@
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+// Error returned by every Tauri command. Serialized as `{ kind, message }`
+// so the frontend can branch on `kind` instead of matching message text.
+#[derive(Debug, thiserror::Error)]
+pub enum LabPulseError {
+    #[error(transparent)]
+    Db(#[from] rusqlite::Error),
+    #[error("{0}")]
+    Import(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    Io(String),
+    #[error("Database connection is unavailable: {0}")]
+    Pool(#[from] r2d2::Error),
+}
+
+impl LabPulseError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            LabPulseError::Db(_) => "db",
+            LabPulseError::Import(_) => "import",
+            LabPulseError::NotFound(_) => "not_found",
+            LabPulseError::Validation(_) => "validation",
+            LabPulseError::Io(_) => "io",
+            LabPulseError::Pool(_) => "pool",
+        }
+    }
+}
+
+impl From<rust_xlsxwriter::XlsxError> for LabPulseError {
+    fn from(e: rust_xlsxwriter::XlsxError) -> Self {
+        LabPulseError::Io(e.to_string())
+    }
+}
+
+impl Serialize for LabPulseError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("LabPulseError", 2)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
@@ -1,6 +1,9 @@
 use calamine::{open_workbook, Reader, Xlsx, Data};
-use rusqlite::{Connection, Result as SqlResult};
+use chrono::Datelike;
+use crate::error::LabPulseError;
+use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ImportSummary {
@@ -11,6 +14,33 @@ pub struct ImportSummary {
     pub warnings: Vec<String>,
 }
 
+// SHA-256 of the file bytes, used to spot the same file being imported twice
+pub fn file_content_hash(file_path: &str) -> Option<String> {
+    use sha2::{Digest, Sha256};
+
+    let bytes = std::fs::read(file_path).ok()?;
+    let digest = Sha256::digest(&bytes);
+    Some(digest.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+// Warning text when a file with this hash already appears in import_log
+pub fn duplicate_import_warning(conn: &Connection, content_hash: Option<&str>) -> Option<String> {
+    let hash = content_hash?;
+    conn.query_row(
+        "SELECT import_type, imported_at FROM import_log
+         WHERE content_hash = ?1
+         ORDER BY imported_at DESC, id DESC LIMIT 1",
+        [hash],
+        |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?)),
+    )
+    .ok()
+    .map(|(import_type, imported_at)| format!(
+        "This file was already imported ({}) on {}",
+        import_type,
+        imported_at.unwrap_or_else(|| "an unknown date".to_string())
+    ))
+}
+
 // Helper function to normalize office ID (strip leading zeros)
 fn normalize_office_id(raw_id: &str) -> Option<i64> {
     raw_id.trim().parse::<i64>().ok()
@@ -36,104 +66,327 @@ fn get_optional_string(cell: &calamine::Data) -> Option<String> {
     }
 }
 
-// Import offices from Office_list.xlsx
-pub fn import_offices(file_path: &str, conn: &Connection) -> SqlResult<ImportSummary> {
-    let mut summary = ImportSummary {
-        filename: file_path.to_string(),
-        rows_processed: 0,
-        rows_inserted: 0,
-        rows_updated: 0,
-        warnings: Vec::new(),
+// Normalize a header for matching: lowercase letters and digits only,
+// so "Office ID", "office_id" and "OFFICE-ID" all compare equal
+fn normalize_header(raw: &str) -> String {
+    raw.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// Map each normalized header name in the first row to its column index
+fn header_columns(header: &[Data]) -> HashMap<String, usize> {
+    let mut columns = HashMap::new();
+    for (idx, cell) in header.iter().enumerate() {
+        let name = normalize_header(&get_string(cell));
+        if !name.is_empty() {
+            columns.entry(name).or_insert(idx);
+        }
+    }
+    columns
+}
+
+// Column of the first alias present in the header
+fn find_column(headers: &HashMap<String, usize>, aliases: &[&str]) -> Option<usize> {
+    aliases.iter().find_map(|alias| headers.get(&normalize_header(alias)).copied())
+}
+
+fn cell_string(row: &[Data], col: usize) -> String {
+    row.get(col).map(get_string).unwrap_or_default()
+}
+
+fn cell_optional_string(row: &[Data], col: Option<usize>) -> Option<String> {
+    col.and_then(|c| row.get(c)).and_then(get_optional_string)
+}
+
+// Accepted header names for each offices-file column; the first is used in warnings
+const OFFICE_ID_HEADERS: &[&str] = &["Office ID", "Practice ID", "ID", "Office Number"];
+const OFFICE_NAME_HEADERS: &[&str] = &["Office Name", "Practice Name", "Name", "Office"];
+const MODEL_HEADERS: &[&str] = &["Model", "Office Model", "Ownership Model"];
+const ADDRESS_HEADERS: &[&str] = &["Address", "Office Address", "Street Address"];
+const PHONE_HEADERS: &[&str] = &["Phone", "Phone Number", "Office Phone"];
+const MANAGING_DENTIST_HEADERS: &[&str] = &["Managing Dentist", "Dentist", "Lead Dentist"];
+const DFO_HEADERS: &[&str] = &["DFO", "Director of Field Operations"];
+const STANDARDIZATION_HEADERS: &[&str] = &["Standardization Status", "Standardization", "Status"];
+
+// Column positions in an offices file, located by header name
+struct OfficeColumns {
+    office_id: usize,
+    office_name: usize,
+    model: usize,
+    address: Option<usize>,
+    phone: Option<usize>,
+    managing_dentist: Option<usize>,
+    dfo: Option<usize>,
+    standardization_status: Option<usize>,
+}
+
+impl OfficeColumns {
+    // Find every column, or list the required headers that are missing
+    fn locate(headers: &HashMap<String, usize>) -> Result<Self, Vec<&'static str>> {
+        let office_id = find_column(headers, OFFICE_ID_HEADERS);
+        let office_name = find_column(headers, OFFICE_NAME_HEADERS);
+        let model = find_column(headers, MODEL_HEADERS);
+
+        match (office_id, office_name, model) {
+            (Some(office_id), Some(office_name), Some(model)) => Ok(OfficeColumns {
+                office_id,
+                office_name,
+                model,
+                address: find_column(headers, ADDRESS_HEADERS),
+                phone: find_column(headers, PHONE_HEADERS),
+                managing_dentist: find_column(headers, MANAGING_DENTIST_HEADERS),
+                dfo: find_column(headers, DFO_HEADERS),
+                standardization_status: find_column(headers, STANDARDIZATION_HEADERS),
+            }),
+            _ => Err([
+                (office_id, OFFICE_ID_HEADERS[0]),
+                (office_name, OFFICE_NAME_HEADERS[0]),
+                (model, MODEL_HEADERS[0]),
+            ]
+            .into_iter()
+            .filter(|(col, _)| col.is_none())
+            .map(|(_, name)| name)
+            .collect()),
+        }
+    }
+}
+
+// Parse a stored hire date: ISO date (optionally with a time), US M/D/YYYY,
+// or a raw Excel date serial left behind by older imports
+pub fn parse_hire_date(raw: &str) -> Option<chrono::NaiveDate> {
+    use chrono::NaiveDate;
+
+    let s = raw.trim();
+    if s.is_empty() {
+        return None;
+    }
+
+    let date_part = s.split([' ', 'T']).next().unwrap_or(s);
+    if let Ok(date) = NaiveDate::parse_from_str(date_part, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(date_part, "%m/%d/%Y") {
+        return Some(date);
+    }
+
+    s.parse::<f64>().ok().and_then(excel_serial_to_date)
+}
+
+// Excel serial: days since 1899-12-30
+fn excel_serial_to_date(serial: f64) -> Option<chrono::NaiveDate> {
+    if serial < 1.0 {
+        return None;
+    }
+    chrono::NaiveDate::from_ymd_opt(1899, 12, 30)?
+        .checked_add_signed(chrono::Duration::days(serial.trunc() as i64))
+}
+
+// Read a hire date cell as ISO YYYY-MM-DD. Date cells and numeric serials are
+// converted; text that doesn't parse as a date is kept as trimmed text.
+fn get_hire_date(cell: &calamine::Data) -> Option<String> {
+    let date = match cell {
+        calamine::Data::DateTime(dt) => excel_serial_to_date(dt.as_f64()),
+        calamine::Data::Float(f) => excel_serial_to_date(*f),
+        calamine::Data::Int(i) => excel_serial_to_date(*i as f64),
+        calamine::Data::DateTimeIso(s) => parse_hire_date(s),
+        calamine::Data::String(s) => match parse_hire_date(s) {
+            Some(date) => Some(date),
+            None => return get_optional_string(cell),
+        },
+        _ => None,
     };
+    date.map(|d| d.format("%Y-%m-%d").to_string())
+}
+
+// Import offices from Office_list.xlsx
+pub fn import_offices(file_path: &str, conn: &Connection) -> Result<ImportSummary, LabPulseError> {
+    let mut summary = new_summary(file_path);
+
+    let content_hash = file_content_hash(file_path);
+    if let Some(warning) = duplicate_import_warning(conn, content_hash.as_deref()) {
+        summary.warnings.push(warning);
+    }
 
     let mut workbook: Xlsx<_> = open_workbook(file_path)
-        .map_err(|e| rusqlite::Error::InvalidQuery)?;
+        .map_err(|e| LabPulseError::Import(format!("Failed to open Excel file: {}", e)))?;
 
     if let Some(Ok(range)) = workbook.worksheet_range_at(0) {
-        // Skip header row, start from row 1 (0-indexed)
-        for (idx, row) in range.rows().enumerate().skip(1) {
-            summary.rows_processed += 1;
+        // Row numbers as the office sheet import has always reported them
+        let rows: Vec<(usize, &[Data])> = range.rows().enumerate().map(|(idx, row)| (idx + 2, row)).collect();
+        import_office_rows(&rows, conn, &mut summary)?;
+    }
 
-            // Column mapping from Office_list.xlsx:
-            // A=Office ID, B=Office Name, C=Model, D=Address, E=Phone, 
-            // F=Managing Dentist, G=DFO, H=Standardization Status
+    log_office_import(conn, &summary, content_hash.as_deref())?;
 
-            if row.len() < 3 {
-                summary.warnings.push(format!("Row {}: Insufficient columns", idx + 2));
-                continue;
-            }
+    Ok(summary)
+}
 
-            // Normalize office ID
-            let office_id = match normalize_office_id(&get_string(&row[0])) {
-                Some(id) => id,
-                None => {
-                    summary.warnings.push(format!("Row {}: Invalid office ID", idx + 2));
-                    continue;
-                }
-            };
+// Import offices from a CSV or tab-separated export of the office list
+pub fn import_offices_csv(file_path: &str, conn: &Connection) -> Result<ImportSummary, LabPulseError> {
+    let mut summary = new_summary(file_path);
 
-            let office_name = get_string(&row[1]);
-            let model = get_string(&row[2]).to_uppercase();
-            
-            // Validate model
-            if model != "PO" && model != "PLLC" {
-                summary.warnings.push(format!(
-                    "Row {}: Invalid model '{}', expected PO or PLLC", 
-                    idx + 2, model
-                ));
-                continue;
+    let content_hash = file_content_hash(file_path);
+    if let Some(warning) = duplicate_import_warning(conn, content_hash.as_deref()) {
+        summary.warnings.push(warning);
+    }
+
+    let contents = std::fs::read_to_string(file_path)
+        .map_err(|e| LabPulseError::Import(format!("Failed to open CSV file: {}", e)))?;
+    let contents = contents.trim_start_matches('\u{feff}');
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(detect_delimiter(contents))
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(contents.as_bytes());
+
+    // Wrap each field as a sheet cell so the xlsx column helpers apply unchanged,
+    // keeping the line each record started on so skipped records don't shift
+    // the row numbers in later warnings
+    let mut rows: Vec<(usize, Vec<Data>)> = Vec::new();
+    for (idx, record) in reader.records().enumerate() {
+        match record {
+            Ok(record) => {
+                let line = record.position().map_or(idx + 1, |pos| pos.line() as usize);
+                rows.push((line, record.iter().map(|f| Data::String(f.to_string())).collect()));
             }
+            Err(e) => summary.warnings.push(format!("Row {}: Unreadable line ({})", idx + 1, e)),
+        }
+    }
 
-            let address = if row.len() > 3 { get_optional_string(&row[3]) } else { None };
-            let phone = if row.len() > 4 { get_optional_string(&row[4]) } else { None };
-            let managing_dentist = if row.len() > 5 { get_optional_string(&row[5]) } else { None };
-            let dfo = if row.len() > 6 { get_optional_string(&row[6]) } else { None };
-            let standardization_status = if row.len() > 7 { 
-                get_optional_string(&row[7]) 
-            } else { 
-                None 
-            };
+    let rows: Vec<(usize, &[Data])> = rows.iter().map(|(line, row)| (*line, row.as_slice())).collect();
+    import_office_rows(&rows, conn, &mut summary)?;
+
+    log_office_import(conn, &summary, content_hash.as_deref())?;
+
+    Ok(summary)
+}
+
+// Tab if the first line has more tabs than commas, otherwise comma
+fn detect_delimiter(contents: &str) -> u8 {
+    let first_line = contents.lines().next().unwrap_or("");
+    let tabs = first_line.matches('\t').count();
+    let commas = first_line.matches(',').count();
+    if tabs > commas {
+        b'\t'
+    } else {
+        b','
+    }
+}
+
+fn new_summary(file_path: &str) -> ImportSummary {
+    ImportSummary {
+        filename: file_path.to_string(),
+        rows_processed: 0,
+        rows_inserted: 0,
+        rows_updated: 0,
+        warnings: Vec::new(),
+    }
+}
 
-            // Upsert office
-            let affected = conn.execute(
-                "INSERT INTO offices (office_id, office_name, model, address, phone, managing_dentist, dfo, standardization_status, updated_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, CURRENT_TIMESTAMP)
-                 ON CONFLICT(office_id) DO UPDATE SET
-                    office_name = excluded.office_name,
-                    model = excluded.model,
-                    address = excluded.address,
-                    phone = excluded.phone,
-                    managing_dentist = excluded.managing_dentist,
-                    dfo = excluded.dfo,
-                    standardization_status = excluded.standardization_status,
-                    updated_at = CURRENT_TIMESTAMP",
-                rusqlite::params![office_id, office_name, model, address, phone, managing_dentist, dfo, standardization_status],
-            )?;
-
-            if affected > 0 {
-                summary.rows_inserted += 1;
+// Validate and upsert office rows, each paired with the row number used in
+// warnings; the first row is the header
+fn import_office_rows(
+    rows: &[(usize, &[Data])],
+    conn: &Connection,
+    summary: &mut ImportSummary,
+) -> Result<(), LabPulseError> {
+    // Columns are located by header name so reordered sheets still import
+    let headers = rows.first().map(|(_, row)| header_columns(row)).unwrap_or_default();
+    let cols = match OfficeColumns::locate(&headers) {
+        Ok(cols) => cols,
+        Err(missing) => {
+            summary.warnings.push(format!(
+                "Missing required column(s): {}; no rows imported",
+                missing.join(", ")
+            ));
+            return Ok(());
+        }
+    };
+
+    // Skip header row
+    for &(line, row) in rows.iter().skip(1) {
+        summary.rows_processed += 1;
+
+        // Normalize office ID
+        let office_id = match normalize_office_id(&cell_string(row, cols.office_id)) {
+            Some(id) => id,
+            None => {
+                summary.warnings.push(format!("Row {}: Invalid office ID", line));
+                continue;
             }
+        };
+
+        let office_name = cell_string(row, cols.office_name);
+        let model = cell_string(row, cols.model).to_uppercase();
+
+        // Validate model
+        if model != "PO" && model != "PLLC" {
+            summary.warnings.push(format!(
+                "Row {}: Invalid model '{}', expected PO or PLLC", 
+                line, model
+            ));
+            continue;
+        }
+
+        let address = cell_optional_string(row, cols.address);
+        let phone = cell_optional_string(row, cols.phone);
+        let managing_dentist = cell_optional_string(row, cols.managing_dentist);
+        let dfo = cell_optional_string(row, cols.dfo);
+        let standardization_status = cell_optional_string(row, cols.standardization_status);
+
+        // Upsert office
+        let affected = conn.execute(
+            "INSERT INTO offices (office_id, office_name, model, address, phone, managing_dentist, dfo, standardization_status, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, CURRENT_TIMESTAMP)
+             ON CONFLICT(office_id) DO UPDATE SET
+                office_name = excluded.office_name,
+                model = excluded.model,
+                address = excluded.address,
+                phone = excluded.phone,
+                managing_dentist = excluded.managing_dentist,
+                dfo = excluded.dfo,
+                standardization_status = excluded.standardization_status,
+                updated_at = CURRENT_TIMESTAMP",
+            rusqlite::params![office_id, office_name, model, address, phone, managing_dentist, dfo, standardization_status],
+        )?;
+
+        if affected > 0 {
+            summary.rows_inserted += 1;
         }
     }
 
-    // Log import
+    Ok(())
+}
+
+fn log_office_import(
+    conn: &Connection,
+    summary: &ImportSummary,
+    content_hash: Option<&str>,
+) -> Result<(), LabPulseError> {
     conn.execute(
-        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings)
-         VALUES ('offices', ?1, ?2, ?3, ?4, ?5)",
+        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings, content_hash)
+         VALUES ('offices', ?1, ?2, ?3, ?4, ?5, ?6)",
         rusqlite::params![
-            file_path,
+            summary.filename,
             summary.rows_processed,
             summary.rows_inserted,
             summary.rows_updated,
-            serde_json::to_string(&summary.warnings).unwrap_or_default()
+            serde_json::to_string(&summary.warnings).unwrap_or_default(),
+            content_hash
         ],
     )?;
-
-    Ok(summary)
+    Ok(())
 }
 
+// Earliest plausible hire year (settings key below); earlier dates are typos
+const HIRE_DATE_MIN_YEAR_KEY: &str = "hire_date_min_year";
+const DEFAULT_HIRE_DATE_MIN_YEAR: f64 = 1970.0;
+
 // Import staff from full_staff_list_per_office.xlsx
-pub fn import_staff(file_path: &str, conn: &Connection) -> SqlResult<ImportSummary> {
+pub fn import_staff(file_path: &str, conn: &Connection) -> Result<ImportSummary, LabPulseError> {
     let mut summary = ImportSummary {
         filename: file_path.to_string(),
         rows_processed: 0,
@@ -142,8 +395,20 @@ pub fn import_staff(file_path: &str, conn: &Connection) -> SqlResult<ImportSumma
         warnings: Vec::new(),
     };
 
+    let content_hash = file_content_hash(file_path);
+    if let Some(warning) = duplicate_import_warning(conn, content_hash.as_deref()) {
+        summary.warnings.push(warning);
+    }
+
     let mut workbook: Xlsx<_> = open_workbook(file_path)
-        .map_err(|e| rusqlite::Error::InvalidQuery)?;
+        .map_err(|e| LabPulseError::Import(format!("Failed to open Excel file: {}", e)))?;
+
+    let min_hire_year = crate::commands::setting_f64(
+        conn,
+        HIRE_DATE_MIN_YEAR_KEY,
+        DEFAULT_HIRE_DATE_MIN_YEAR,
+    ) as i32;
+    let today = chrono::Local::now().date_naive();
 
     if let Some(Ok(range)) = workbook.worksheet_range_at(0) {
         // Skip header (row 0) and blank row (row 1), start from row 2
@@ -172,7 +437,25 @@ pub fn import_staff(file_path: &str, conn: &Connection) -> SqlResult<ImportSumma
                 job_title = job_title[5..].to_string();
             }
 
-            let hire_date = if row.len() > 3 { get_optional_string(&row[3]) } else { None };
+            let mut hire_date = if row.len() > 3 { get_hire_date(&row[3]) } else { None };
+
+            // Store NULL rather than a date that can't be right
+            if let Some(date) = hire_date.as_deref().and_then(parse_hire_date) {
+                let problem = if date > today {
+                    Some("is in the future")
+                } else if date.year() < min_hire_year {
+                    Some("is before the earliest allowed hire year")
+                } else {
+                    None
+                };
+                if let Some(problem) = problem {
+                    summary.warnings.push(format!(
+                        "Row {}: Hire date {} {}; left blank",
+                        idx + 3, date, problem
+                    ));
+                    hire_date = None;
+                }
+            }
 
             // Check if office exists
             let office_exists: bool = conn.query_row(
@@ -206,14 +489,15 @@ pub fn import_staff(file_path: &str, conn: &Connection) -> SqlResult<ImportSumma
 
     // Log import
     conn.execute(
-        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings)
-         VALUES ('staff', ?1, ?2, ?3, ?4, ?5)",
+        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings, content_hash)
+         VALUES ('staff', ?1, ?2, ?3, ?4, ?5, ?6)",
         rusqlite::params![
             file_path,
             summary.rows_processed,
             summary.rows_inserted,
             summary.rows_updated,
-            serde_json::to_string(&summary.warnings).unwrap_or_default()
+            serde_json::to_string(&summary.warnings).unwrap_or_default(),
+            content_hash
         ],
     )?;
 
@@ -221,7 +505,7 @@ pub fn import_staff(file_path: &str, conn: &Connection) -> SqlResult<ImportSumma
 }
 
 // Import contacts from Lab_manager_Contact_List.xlsx
-pub fn import_contacts(file_path: &str, conn: &Connection) -> SqlResult<ImportSummary> {
+pub fn import_contacts(file_path: &str, conn: &Connection) -> Result<ImportSummary, LabPulseError> {
     let mut summary = ImportSummary {
         filename: file_path.to_string(),
         rows_processed: 0,
@@ -230,8 +514,13 @@ pub fn import_contacts(file_path: &str, conn: &Connection) -> SqlResult<ImportSu
         warnings: Vec::new(),
     };
 
+    let content_hash = file_content_hash(file_path);
+    if let Some(warning) = duplicate_import_warning(conn, content_hash.as_deref()) {
+        summary.warnings.push(warning);
+    }
+
     let mut workbook: Xlsx<_> = open_workbook(file_path)
-        .map_err(|e| rusqlite::Error::InvalidQuery)?;
+        .map_err(|e| LabPulseError::Import(format!("Failed to open Excel file: {}", e)))?;
 
     if let Some(Ok(range)) = workbook.worksheet_range_at(0) {
         // Skip header row
@@ -285,17 +574,75 @@ pub fn import_contacts(file_path: &str, conn: &Connection) -> SqlResult<ImportSu
 
     // Log import
     conn.execute(
-        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings)
-         VALUES ('contacts', ?1, ?2, ?3, ?4, ?5)",
+        "INSERT INTO import_log (import_type, filename, rows_processed, rows_inserted, rows_updated, warnings, content_hash)
+         VALUES ('contacts', ?1, ?2, ?3, ?4, ?5, ?6)",
         rusqlite::params![
             file_path,
             summary.rows_processed,
             summary.rows_inserted,
             summary.rows_updated,
-            serde_json::to_string(&summary.warnings).unwrap_or_default()
+            serde_json::to_string(&summary.warnings).unwrap_or_default(),
+            content_hash
         ],
     )?;
 
     Ok(summary)
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrated_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        crate::db::run_migrations(&conn).unwrap();
+        conn
+    }
+
+    #[test]
+    fn import_offices_csv_reports_the_line_a_bad_record_starts_on() {
+        let conn = migrated_connection();
+        let path = std::env::temp_dir().join("labpulse_offices_multiline.csv");
+        std::fs::write(
+            &path,
+            "Office ID,Office Name,Model,Address\n\
+             1,North,PO,\"12 Main St\nSuite 4\nFloor 2\"\n\
+             2,South,XX,\n\
+             3,East,PLLC,\n",
+        )
+        .unwrap();
+
+        let summary = import_offices_csv(&path.to_string_lossy(), &conn).unwrap();
+
+        assert_eq!(summary.rows_processed, 3);
+        assert_eq!(summary.rows_inserted, 2);
+        assert!(summary.warnings.iter().any(|w| w.starts_with("Row 5: Invalid model 'XX'")), "{:?}", summary.warnings);
+    }
+
+    #[test]
+    fn import_staff_blanks_a_future_hire_date() {
+        let conn = migrated_connection();
+        conn.execute("INSERT INTO offices (office_id, office_name, model) VALUES (1, 'North', 'PO')", []).unwrap();
+
+        // Header, blank row, then one staff member per row
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.write_row(0, 0, ["Practice ID", "Name", "Job Title", "Hire Date"]).unwrap();
+        sheet.write_row(2, 0, ["001", "Ann", "Technician", "2020-05-01"]).unwrap();
+        sheet.write_row(3, 0, ["001", "Bo", "ADDL Technician", "2999-01-15"]).unwrap();
+        let path = std::env::temp_dir().join("labpulse_staff_future_hire.xlsx");
+        workbook.save(&path).unwrap();
+
+        let summary = import_staff(&path.to_string_lossy(), &conn).unwrap();
+
+        assert_eq!(summary.rows_inserted, 2);
+        assert!(summary.warnings.iter().any(|w| w.contains("2999-01-15 is in the future")));
+
+        let hire_date = |name: &str| -> Option<String> {
+            conn.query_row("SELECT hire_date FROM staff WHERE name = ?1", [name], |row| row.get(0)).unwrap()
+        };
+        assert_eq!(hire_date("Ann").as_deref(), Some("2020-05-01"));
+        assert_eq!(hire_date("Bo"), None);
+    }
+}
@@ -4,10 +4,10 @@
 mod db;
 mod commands;
 mod imports;
+mod error;
 
 use tauri::Manager;
 use commands::DbConnection;
-use std::sync::Mutex;
 
 fn main() {
     tauri::Builder::default()
@@ -17,7 +17,7 @@ fn main() {
             // Initialize database on app startup
             let app_handle = app.handle().clone();
             match db::init_db(&app_handle) {
-                Ok(conn) => {
+                Ok(pool) => {
                     println!("✓ Database initialized successfully");
                     
                     // Get and print database path for debugging
@@ -26,8 +26,8 @@ fn main() {
                     let db_path = app_dir.join("labpulse.db");
                     println!("✓ Database location: {}", db_path.display());
                     
-                    // Store connection in app state for commands to use
-                    app.manage(DbConnection(Mutex::new(conn)));
+                    // Store the connection pool in app state for commands to use
+                    app.manage(DbConnection(pool));
                 },
                 Err(e) => {
                     eprintln!("✗ Failed to initialize database: {}", e);
@@ -44,7 +44,9 @@ fn main() {
             commands::import_staff_file,
             commands::import_contacts_file,
             commands::import_bulk_financials,
+            commands::preview_bulk_financials,
             commands::import_bulk_weekly_volume,
+            commands::rebuild_all_aggregates,
             commands::save_financial_data,
             commands::get_financial_data,
             commands::get_previous_month_financial,
@@ -66,6 +68,119 @@ fn main() {
             commands::remove_office,
             commands::add_office_from_template,
             commands::get_compliance_data,
+            commands::get_tier_revenue_estimate,
+            commands::clear_office_month,
+            commands::get_overtime_backlog_correlation,
+            commands::find_volume_total_mismatches,
+            commands::repair_volume_totals,
+            commands::get_dfo_list,
+            commands::export_alerts_csv,
+            commands::rank_by_margin,
+            commands::apply_financial_adjustments,
+            commands::get_lab_vs_clinic_split,
+            commands::get_company_lab_vs_clinic_split,
+            commands::get_dashboard_custom,
+            commands::check_supply_reconciliation,
+            commands::get_latest_notes,
+            commands::get_staffing_trend_series,
+            commands::get_overtime_vs_model,
+            commands::get_average_tenure,
+            commands::get_tenure_rankings,
+            commands::export_office_report_html,
+            commands::get_quarterly_volume,
+            commands::get_peak_revenue_month,
+            commands::get_all_peak_revenue_months,
+            commands::get_volume_revenue_ratio_trend,
+            commands::record_counts_snapshot,
+            commands::get_count_history,
+            commands::find_data_gaps,
+            commands::get_rank_change,
+            commands::get_office_health_score,
+            commands::get_most_improved,
+            commands::forecast_next_month,
+            commands::get_overtime_recon_series,
+            commands::get_volume_grouping,
+            commands::find_expense_anomalies,
+            commands::generate_alerts,
+            commands::generate_all_alerts,
+            commands::save_view,
+            commands::list_views,
+            commands::get_view,
+            commands::delete_view,
+            commands::get_alerts,
+            commands::get_company_outside_lab_trend,
+            commands::dismiss_alert,
+            commands::reopen_alert,
+            commands::dismiss_all_alerts,
+            commands::get_alert_resolution_stats,
+            commands::find_missing_rollups,
+            commands::get_alert_thresholds,
+            commands::set_alert_thresholds,
+            commands::get_seasonal_index,
+            commands::create_office,
+            commands::update_office,
+            commands::delete_office,
+            commands::get_staff,
+            commands::add_staff,
+            commands::update_staff,
+            commands::delete_staff,
+            commands::get_overtime_share_of_growth,
+            commands::get_contacts,
+            commands::add_contact,
+            commands::update_contact,
+            commands::delete_contact,
+            commands::export_comparison_matrix_xlsx,
+            commands::get_setting,
+            commands::set_setting,
+            commands::get_all_settings,
+            commands::get_breach_streak,
+            commands::get_import_log,
+            commands::get_ytd_vs_prior,
+            commands::export_financials,
+            commands::get_standardization_progress,
+            commands::export_dashboard_csv,
+            commands::set_baseline,
+            commands::get_variance_to_baseline,
+            commands::delete_financial_data,
+            commands::delete_operations_data,
+            commands::delete_volume_data,
+            commands::get_markup,
+            commands::get_markup_distribution,
+            commands::get_financial_history,
+            commands::get_company_periods,
+            commands::get_year_over_year,
+            commands::get_tier_trend,
+            commands::get_ytd_financials,
+            commands::get_overtime_per_backlog,
+            commands::get_ttm_financials,
+            commands::find_future_periods,
+            commands::find_similar_offices,
+            commands::get_revenue_weighted_metric,
+            commands::find_incomplete_office_profiles,
+            commands::get_tier_growth,
+            commands::get_company_margin_dollars,
+            commands::snapshot_rankings,
+            commands::get_ranking_snapshot,
+            commands::import_bulk_targets,
+            commands::get_targets,
+            commands::backup_database,
+            commands::get_rework_rate,
+            commands::rank_by_rework_rate,
+            commands::restore_database,
+            commands::find_unprofitable_offices,
+            commands::get_staffing_backlog_relationship,
+            commands::get_quarterly_financials,
+            commands::get_company_summary,
+            commands::get_dfo_rollup,
+            commands::get_model_comparison,
+            commands::import_bulk_operations,
+            commands::copy_previous_month_financials,
+            commands::copy_previous_month_operations,
+            commands::copy_previous_month_volume,
+            commands::save_month_bundle,
+            commands::db_health_check,
+            commands::optimize_database,
+            commands::get_data_gaps,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
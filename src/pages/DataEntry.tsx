@@ -7,6 +7,7 @@ import FinancialEntryForm from '../components/FinancialEntryForm';
 import VolumeEntryForm from '../components/VolumeEntryForm';
 import NotesSection from '../components/NotesSection';
 import { DataEntryTab } from '../types/DataEntry';
+import { errorMessage } from '../utils/errors';
 
 interface ImportSummary {
   filename: string;
@@ -21,6 +22,7 @@ export default function DataEntry() {
   const [importing, setImporting] = useState<string>('');
   const [lastImport, setLastImport] = useState<ImportSummary | null>(null);
   const [showImportSection, setShowImportSection] = useState(false);
+  const [overwriteWeeks, setOverwriteWeeks] = useState(false);
 
   // Data entry state
   const [selectedOffice, setSelectedOffice] = useState<number | null>(null);
@@ -88,22 +90,24 @@ export default function DataEntry() {
     }
   }, [selectedOffice, selectedYear, selectedMonth]);
 
-  const handleImport = async (importType: 'offices' | 'staff' | 'contacts' | 'bulk_financials' | 'bulk_weekly_volume') => {
+  const handleImport = async (importType: 'offices' | 'staff' | 'contacts' | 'bulk_financials' | 'bulk_operations' | 'bulk_weekly_volume') => {
     setImporting(importType);
     setLastImport(null);
 
     try {
       const selected = await open({
-        filters: [{
-          name: 'Excel Files',
-          extensions: ['xlsx', 'xls']
-        }],
+        filters: [importType === 'offices'
+          ? { name: 'Spreadsheet Files', extensions: ['xlsx', 'xls', 'csv', 'tsv', 'txt'] }
+          : { name: 'Excel Files', extensions: ['xlsx', 'xls'] }
+        ],
         multiple: false,
         directory: false,
         title: importType === 'bulk_financials' 
           ? 'Select labpulse_import.xlsx file' 
           : importType === 'bulk_weekly_volume'
           ? 'Select backlog tracker file'
+          : importType === 'offices'
+          ? 'Select offices XLSX or CSV file'
           : `Select ${importType} XLSX file`
       });
 
@@ -119,8 +123,10 @@ export default function DataEntry() {
         result = await invoke<ImportSummary>('import_staff_file', { filePath: selected });
       } else if (importType === 'contacts') {
         result = await invoke<ImportSummary>('import_contacts_file', { filePath: selected });
+      } else if (importType === 'bulk_operations') {
+        result = await invoke<ImportSummary>('import_bulk_operations', { filePath: selected });
       } else if (importType === 'bulk_weekly_volume') {
-        result = await invoke<ImportSummary>('import_bulk_weekly_volume', { filePath: selected });
+        result = await invoke<ImportSummary>('import_bulk_weekly_volume', { filePath: selected, overwrite: overwriteWeeks });
       } else {
         result = await invoke<ImportSummary>('import_bulk_financials', { filePath: selected });
       }
@@ -128,7 +134,7 @@ export default function DataEntry() {
       setLastImport(result);
     } catch (err) {
       console.error('Import error:', err);
-      alert(`Import failed: ${err}`);
+      alert(`Import failed: ${errorMessage(err)}`);
     } finally {
       setImporting('');
     }
@@ -588,11 +594,33 @@ export default function DataEntry() {
                 </button>
               </div>
 
+              <div className="border border-gray-200 rounded-lg p-4">
+                <h3 className="font-semibold mb-2">Bulk Operations Data</h3>
+                <p className="text-sm text-gray-600 mb-4">
+                  Import monthly backlog, overtime and staffing from Excel file.
+                </p>
+                <button
+                  onClick={() => handleImport('bulk_operations')}
+                  disabled={importing !== ''}
+                  className="w-full px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:bg-gray-400 transition-colors"
+                >
+                  {importing === 'bulk_operations' ? 'Importing...' : 'Import Operations'}
+                </button>
+              </div>
+
               <div className="border border-gray-200 rounded-lg p-4">
                 <h3 className="font-semibold mb-2">Bulk Weekly Volume</h3>
                 <p className="text-sm text-gray-600 mb-4">
                   Import weekly volume data from backlog tracker.
                 </p>
+                <label className="flex items-center gap-2 text-sm text-gray-700 mb-3">
+                  <input
+                    type="checkbox"
+                    checked={overwriteWeeks}
+                    onChange={(e) => setOverwriteWeeks(e.target.checked)}
+                  />
+                  Replace weeks that were already imported
+                </label>
                 <button
                   onClick={() => handleImport('bulk_weekly_volume')}
                   disabled={importing !== ''}
@@ -2,6 +2,7 @@ import { useState, useEffect } from 'react';
 import { useNavigate } from 'react-router-dom';
 import { invoke } from '@tauri-apps/api/core';
 import * as XLSX from 'xlsx';
+import { errorMessage } from '../utils/errors';
 
 interface Office {
   office_id: number;
@@ -165,7 +166,7 @@ export default function Directory() {
       }, 3000);
     } catch (err) {
       console.error('Failed to remove office:', err);
-      setRemoveError(err as string || 'Failed to remove office');
+      setRemoveError(errorMessage(err, 'Failed to remove office'));
       setTimeout(() => {
         setRemoveError(null);
       }, 5000);
@@ -466,7 +467,7 @@ export default function Directory() {
       }, 3000);
     } catch (err) {
       console.error('Failed to import office:', err);
-      setImportError(err instanceof Error ? err.message : 'Failed to import office');
+      setImportError(errorMessage(err, 'Failed to import office'));
     } finally {
       setImporting(false);
     }
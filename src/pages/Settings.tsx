@@ -1,5 +1,7 @@
 import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { open, save } from '@tauri-apps/plugin-dialog';
+import { errorMessage } from '../utils/errors';
 
 interface TableCounts {
   offices: number;
@@ -12,11 +14,27 @@ interface TableCounts {
   alerts: number;
 }
 
+interface HealthReport {
+  ok: boolean;
+  db_path: string;
+  integrity_check: string[];
+  schema_version: number;
+  table_counts: { table: string; rows: number }[];
+  sqlite_version: string;
+}
+
 export default function Settings() {
   const [tableCounts, setTableCounts] = useState<TableCounts | null>(null);
   const [dbPath, setDbPath] = useState<string>('');
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState<string>('');
+  const [backingUp, setBackingUp] = useState(false);
+  const [backupMessage, setBackupMessage] = useState<string>('');
+  const [restoring, setRestoring] = useState(false);
+  const [health, setHealth] = useState<HealthReport | null>(null);
+  const [checkingHealth, setCheckingHealth] = useState(false);
+  const [optimizing, setOptimizing] = useState(false);
+  const [rebuilding, setRebuilding] = useState(false);
 
   const loadSanityCheck = async () => {
     setLoading(true);
@@ -27,13 +45,110 @@ export default function Settings() {
       setTableCounts(counts);
       setDbPath(path);
     } catch (err) {
-      setError(err as string);
+      setError(errorMessage(err));
       console.error('Failed to load sanity check:', err);
     } finally {
       setLoading(false);
     }
   };
 
+  const handleBackup = async () => {
+    const date = new Date().toISOString().split('T')[0];
+    const destPath = await save({
+      defaultPath: `labpulse_backup_${date}.db`,
+      filters: [{ name: 'SQLite Database', extensions: ['db'] }],
+      title: 'Save database backup'
+    });
+    if (!destPath) return;
+
+    setBackingUp(true);
+    setError('');
+    setBackupMessage('');
+    try {
+      const bytes = await invoke<number>('backup_database', { destPath });
+      setBackupMessage(`Backup saved to ${destPath} (${(bytes / 1024 / 1024).toFixed(1)} MB)`);
+    } catch (err) {
+      setError(errorMessage(err, 'Backup failed'));
+      console.error('Failed to back up database:', err);
+    } finally {
+      setBackingUp(false);
+    }
+  };
+
+  const handleRestore = async () => {
+    const srcPath = await open({
+      filters: [{ name: 'SQLite Database', extensions: ['db'] }],
+      multiple: false,
+      directory: false,
+      title: 'Select database backup to restore'
+    });
+    if (!srcPath) return;
+    if (!confirm('Restoring replaces ALL current data with the contents of the backup. Continue?')) return;
+
+    setRestoring(true);
+    setError('');
+    setBackupMessage('');
+    try {
+      await invoke('restore_database', { srcPath });
+      setBackupMessage(`Database restored from ${srcPath}`);
+      await loadSanityCheck();
+    } catch (err) {
+      setError(errorMessage(err, 'Restore failed'));
+      console.error('Failed to restore database:', err);
+    } finally {
+      setRestoring(false);
+    }
+  };
+
+  const handleOptimize = async () => {
+    if (!confirm('Optimizing rewrites the database file. Make sure no imports or saves are running. Continue?')) return;
+
+    setOptimizing(true);
+    setError('');
+    setBackupMessage('');
+    try {
+      const result = await invoke<{ size_before: number; size_after: number }>('optimize_database');
+      const mb = (bytes: number) => (bytes / 1024 / 1024).toFixed(1);
+      setBackupMessage(`Database optimized: ${mb(result.size_before)} MB → ${mb(result.size_after)} MB`);
+    } catch (err) {
+      setError(errorMessage(err, 'Optimize failed'));
+      console.error('Failed to optimize database:', err);
+    } finally {
+      setOptimizing(false);
+    }
+  };
+
+  const handleRebuildVolume = async () => {
+    if (!confirm('Rebuild every month of volume from the weekly records? Monthly volume edited by hand for those months will be replaced. Continue?')) return;
+
+    setRebuilding(true);
+    setError('');
+    setBackupMessage('');
+    try {
+      const months = await invoke<number>('rebuild_all_aggregates');
+      setBackupMessage(`Rebuilt monthly volume for ${months} office-month(s) from weekly data`);
+      await loadSanityCheck();
+    } catch (err) {
+      setError(errorMessage(err, 'Rebuild failed'));
+      console.error('Failed to rebuild monthly volume:', err);
+    } finally {
+      setRebuilding(false);
+    }
+  };
+
+  const handleHealthCheck = async () => {
+    setCheckingHealth(true);
+    setError('');
+    try {
+      setHealth(await invoke<HealthReport>('db_health_check'));
+    } catch (err) {
+      setError(errorMessage(err, 'Health check failed'));
+      console.error('Failed to run health check:', err);
+    } finally {
+      setCheckingHealth(false);
+    }
+  };
+
   useEffect(() => {
     loadSanityCheck();
   }, []);
@@ -46,13 +161,22 @@ export default function Settings() {
       <div className="bg-white rounded-lg shadow p-6 mb-6">
         <div className="flex items-center justify-between mb-4">
           <h2 className="text-xl font-semibold">Database Sanity Check</h2>
-          <button
-            onClick={loadSanityCheck}
-            disabled={loading}
-            className="px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:bg-gray-400 transition-colors"
-          >
-            {loading ? 'Loading...' : 'Refresh'}
-          </button>
+          <div>
+            <button
+              onClick={loadSanityCheck}
+              disabled={loading}
+              className="px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:bg-gray-400 transition-colors"
+            >
+              {loading ? 'Loading...' : 'Refresh'}
+            </button>
+            <button
+              onClick={handleHealthCheck}
+              disabled={checkingHealth}
+              className="ml-3 px-4 py-2 bg-gray-700 text-white rounded hover:bg-gray-800 disabled:bg-gray-400 transition-colors"
+            >
+              {checkingHealth ? 'Checking...' : 'Run Health Check'}
+            </button>
+          </div>
         </div>
 
         {error && (
@@ -61,12 +185,55 @@ export default function Settings() {
           </div>
         )}
 
+        {health && (
+          <div className={`border px-4 py-3 rounded mb-4 text-sm ${health.ok ? 'bg-green-50 border-green-200 text-green-800' : 'bg-red-50 border-red-200 text-red-700'}`}>
+            <strong>{health.ok ? 'Database OK' : 'Integrity problems found'}</strong>
+            {' '}· schema v{health.schema_version} · SQLite {health.sqlite_version} · {health.table_counts.length} tables
+            {!health.ok && (
+              <ul className="mt-2 list-disc list-inside">
+                {health.integrity_check.map((line, i) => <li key={i}>{line}</li>)}
+              </ul>
+            )}
+          </div>
+        )}
+
         {/* Database Path */}
         <div className="mb-6">
           <h3 className="text-sm font-semibold text-gray-700 mb-2">Database Location</h3>
           <code className="block bg-gray-100 p-3 rounded text-sm break-all">
             {dbPath || 'Loading...'}
           </code>
+          <button
+            onClick={handleBackup}
+            disabled={backingUp}
+            className="mt-3 px-4 py-2 bg-green-600 text-white rounded hover:bg-green-700 disabled:bg-gray-400 transition-colors"
+          >
+            {backingUp ? 'Backing up...' : 'Back Up Database'}
+          </button>
+          <button
+            onClick={handleRestore}
+            disabled={restoring}
+            className="mt-3 ml-3 px-4 py-2 bg-red-600 text-white rounded hover:bg-red-700 disabled:bg-gray-400 transition-colors"
+          >
+            {restoring ? 'Restoring...' : 'Restore from Backup'}
+          </button>
+          <button
+            onClick={handleOptimize}
+            disabled={optimizing}
+            className="mt-3 ml-3 px-4 py-2 bg-gray-700 text-white rounded hover:bg-gray-800 disabled:bg-gray-400 transition-colors"
+          >
+            {optimizing ? 'Optimizing...' : 'Optimize Database'}
+          </button>
+          <button
+            onClick={handleRebuildVolume}
+            disabled={rebuilding}
+            className="mt-3 ml-3 px-4 py-2 bg-gray-700 text-white rounded hover:bg-gray-800 disabled:bg-gray-400 transition-colors"
+          >
+            {rebuilding ? 'Rebuilding...' : 'Rebuild Monthly Volume'}
+          </button>
+          {backupMessage && (
+            <p className="mt-2 text-sm text-green-700">{backupMessage}</p>
+          )}
         </div>
 
         {/* Table Counts */}
@@ -0,0 +1,19 @@
+// Error shape returned by every Tauri command (LabPulseError in the backend)
+export type LabPulseErrorKind = 'db' | 'import' | 'not_found' | 'validation' | 'io' | 'pool';
+
+export interface LabPulseError {
+  kind: LabPulseErrorKind;
+  message: string;
+}
+
+export function isLabPulseError(err: unknown): err is LabPulseError {
+  return typeof err === 'object' && err !== null && 'kind' in err && 'message' in err;
+}
+
+// Human-readable message for anything thrown by invoke()
+export function errorMessage(err: unknown, fallback = 'Something went wrong'): string {
+  if (isLabPulseError(err)) return err.message;
+  if (err instanceof Error) return err.message;
+  if (typeof err === 'string' && err) return err;
+  return fallback;
+}